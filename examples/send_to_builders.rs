@@ -46,7 +46,12 @@ async fn main() -> Result<()> {
     // Broadcast the bundle to all builders setup above!
     let responses = provider.send_eth_bundle(bundle, &endpoints).await;
 
-    println!("{responses:#?}");
+    for response in responses {
+        match response.result {
+            Ok(hash) => println!("{}: {}", response.endpoint_name, hash.bundle_hash),
+            Err(err) => println!("{} failed: {err}", response.endpoint_name),
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use alloy::transports::TransportResult;
+use url::Url;
+
+use super::Endpoint;
+
+/// The result of a request broadcast to a single endpoint.
+#[derive(Debug)]
+pub struct BroadcastResponse<Resp> {
+    /// The name of the endpoint the request has been sent to.
+    pub endpoint_name: String,
+    /// The URL of the endpoint the request has been sent to.
+    pub url: Url,
+    /// The time the endpoint took to answer.
+    pub latency: Duration,
+    /// The response of the endpoint.
+    pub result: TransportResult<Resp>,
+}
+
+impl<Resp> BroadcastResponse<Resp> {
+    pub(crate) fn new(
+        endpoint: &Endpoint,
+        latency: Duration,
+        result: TransportResult<Resp>,
+    ) -> Self {
+        Self {
+            endpoint_name: endpoint.name.clone(),
+            url: endpoint.url.clone(),
+            latency,
+            result,
+        }
+    }
+
+    /// Returns `true` if the endpoint answered successfully.
+    pub const fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// Returns `true` if the endpoint answered with a JSON-RPC error, rather
+    /// than failing to answer, e.g. because of a timeout or a connection
+    /// reset.
+    pub fn is_rejected(&self) -> bool {
+        self.result.as_ref().is_err_and(|err| err.is_error_resp())
+    }
+}
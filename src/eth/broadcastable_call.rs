@@ -1,26 +1,43 @@
-use std::{fmt::Debug, future::IntoFuture, marker::PhantomData, pin::Pin};
+use std::{
+    fmt::Debug,
+    pin::Pin,
+    time::{Duration, Instant},
+};
 
 use alloy::{
-    providers::ext::MevBuilder,
-    rpc::{
-        client::RpcCall,
-        json_rpc::{Request, RpcObject},
-    },
-    transports::{BoxFuture, TransportResult},
+    rpc::json_rpc::{Request, RpcObject},
+    transports::{BoxFuture, TransportErrorKind, TransportResult},
+};
+use futures::{
+    future::join_all,
+    stream::{self, BoxStream, FuturesUnordered},
+    Future, FutureExt, StreamExt,
 };
-use futures::{future::join_all, Future, FutureExt};
 use pin_project::pin_project;
 
-use crate::utils::build_rpc_client;
-
-use super::Endpoints;
+use super::{BroadcastResponse, Endpoint, Endpoints};
 
 /// Allows to broadcast a request to many RPC endpoints.
+///
+/// By default, the call resolves once every endpoint has answered. A
+/// per-endpoint timeout and an overall deadline can be set, after which
+/// unfinished endpoints are reported as timed out instead of being awaited.
+///
+/// Instead of awaiting all the endpoints, results can also be consumed as
+/// soon as each endpoint answers using [`BroadcastableCall::into_stream`].
+///
+/// Endpoints having a [`RetryPolicy`] retry their request on transient
+/// errors, until the timeout or the deadline is reached.
+///
+/// [`RetryPolicy`]: crate::RetryPolicy
 #[pin_project]
 pub struct BroadcastableCall<Params, Resp> {
+    endpoints: Vec<Endpoint>,
+    request: Request<Params>,
+    timeout: Option<Duration>,
+    deadline: Option<Instant>,
     #[pin]
-    fut: BoxFuture<'static, Vec<TransportResult<Resp>>>,
-    phantom: PhantomData<Params>,
+    fut: Option<BoxFuture<'static, Vec<BroadcastResponse<Resp>>>>,
 }
 
 impl<Params, Resp> BroadcastableCall<Params, Resp>
@@ -30,39 +47,134 @@ where
 {
     /// Creates a new [`BroadcastableCall`].
     pub fn new(endpoints: &Endpoints, request: Request<Params>) -> Self {
-        let calls = endpoints
-            .iter()
-            .map(|e| {
-                let client = build_rpc_client(e.url.clone());
-                let rpc_call = RpcCall::new(request.clone(), client.transport().clone());
-                let mut mev = MevBuilder::new_rpc(rpc_call);
-                if let Some(signer) = &e.signer {
-                    mev = mev.with_auth(signer.clone())
-                }
-                mev.into_future()
-            })
-            .collect::<Vec<_>>();
-
         Self {
-            fut: join_all(calls).boxed(),
-            phantom: PhantomData::<Params>,
+            endpoints: endpoints.iter().cloned().collect(),
+            request,
+            timeout: None,
+            deadline: None,
+            fut: None,
         }
     }
+
+    fn calls(
+        endpoints: Vec<Endpoint>,
+        request: &Request<Params>,
+        limit: Option<Instant>,
+    ) -> impl Iterator<Item = impl Future<Output = BroadcastResponse<Resp>>> + '_ {
+        endpoints.into_iter().map(move |endpoint| {
+            let call = endpoint.call(request.clone(), limit);
+            with_limit(endpoint, call, limit)
+        })
+    }
+}
+
+impl<Params, Resp> BroadcastableCall<Params, Resp> {
+    /// Sets the maximum duration each endpoint has to answer, starting from
+    /// the moment the call is first polled.
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Sets the instant after which all endpoints that have not answered yet
+    /// are reported as timed out.
+    pub const fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+
+        self
+    }
 }
 
-impl<Params, Resp> Future for BroadcastableCall<Params, Resp> {
-    type Output = Vec<TransportResult<Resp>>;
+impl<Params, Resp> BroadcastableCall<Params, Resp>
+where
+    Params: RpcObject,
+    Resp: RpcObject,
+{
+    /// Converts this call into a stream yielding each endpoint's response as
+    /// soon as it is available.
+    ///
+    /// As when awaiting the call, the timeout starts when the stream is
+    /// first polled.
+    pub fn into_stream(self) -> BoxStream<'static, BroadcastResponse<Resp>> {
+        let Self {
+            endpoints,
+            request,
+            timeout,
+            deadline,
+            ..
+        } = self;
+
+        stream::once(async move {
+            let limit = limit(timeout, deadline);
+
+            Self::calls(endpoints, &request, limit).collect::<FuturesUnordered<_>>()
+        })
+        .flatten()
+        .boxed()
+    }
+}
+
+impl<Params, Resp> Future for BroadcastableCall<Params, Resp>
+where
+    Params: RpcObject,
+    Resp: RpcObject,
+{
+    type Output = Vec<BroadcastResponse<Resp>>;
 
     fn poll(
         self: Pin<&mut Self>,
         cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Self::Output> {
-        self.project().fut.poll(cx)
+        let mut this = self.project();
+
+        if this.fut.is_none() {
+            let limit = limit(*this.timeout, *this.deadline);
+            let calls = Self::calls(std::mem::take(this.endpoints), this.request, limit);
+
+            this.fut.set(Some(join_all(calls).boxed()));
+        }
+
+        this.fut
+            .as_pin_mut()
+            .expect("the broadcast future has been set above")
+            .poll(cx)
     }
 }
 
 impl<Params, Resp> Debug for BroadcastableCall<Params, Resp> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("BroadcastableCall").finish()
+        f.debug_struct("BroadcastableCall")
+            .field("timeout", &self.timeout)
+            .field("deadline", &self.deadline)
+            .finish()
+    }
+}
+
+/// Returns the instant at which pending calls should be abandoned, if any. A
+/// timeout too large to be represented is ignored.
+fn limit(timeout: Option<Duration>, deadline: Option<Instant>) -> Option<Instant> {
+    let timeout = timeout.and_then(|t| Instant::now().checked_add(t));
+
+    match (timeout, deadline) {
+        (Some(timeout), Some(deadline)) => Some(timeout.min(deadline)),
+        (timeout, deadline) => timeout.or(deadline),
     }
 }
+
+async fn with_limit<Resp>(
+    endpoint: Endpoint,
+    call: BoxFuture<'static, TransportResult<Resp>>,
+    limit: Option<Instant>,
+) -> BroadcastResponse<Resp> {
+    let start = Instant::now();
+
+    let result = match limit {
+        Some(limit) => tokio::time::timeout_at(limit.into(), call)
+            .await
+            .unwrap_or_else(|_| Err(TransportErrorKind::custom_str("request timed out"))),
+        None => call.await,
+    };
+
+    BroadcastResponse::new(&endpoint, start.elapsed(), result)
+}
@@ -1,17 +1,29 @@
-use std::marker::PhantomData;
+use std::{marker::PhantomData, ops::RangeInclusive};
 
 use alloy::{
+    consensus::BlockHeader,
     eips::Encodable2718,
-    network::Network,
-    primitives::{Bytes, B256},
+    network::{BlockResponse, Network, TransactionBuilder},
+    primitives::{keccak256, Address, Bytes, B256, U256},
     providers::{
         fillers::{FillProvider, TxFiller},
         Provider,
     },
-    rpc::types::mev::EthSendBundle,
+    rpc::types::{
+        mev::{EthBundleHash, EthCallBundle, EthSendBundle},
+        BlockNumberOrTag,
+    },
     transports::{TransportErrorKind, TransportResult},
 };
 
+use futures::try_join;
+
+use super::{
+    BroadcastResponse, BundleHandle, BundleTransaction, CoinbaseTip, Endpoints, EthMevProviderExt,
+    FeeStrategy, NonceTracker, SimulationCriteria, SimulationError, SimulationReport,
+    TransactionFlags,
+};
+
 /// A bundle that can be sent to one or more builder(s).
 #[derive(Debug)]
 pub struct EthBundleBuilder<'a, P, N>
@@ -21,6 +33,15 @@ where
 {
     provider: &'a P,
     bundle: EthSendBundle,
+    target_blocks: Option<RangeInclusive<u64>>,
+    transactions: Vec<BundleTransaction>,
+    nonces: NonceTracker,
+    chain_id: Option<u64>,
+    state_block: Option<BlockNumberOrTag>,
+    simulation_coinbase: Option<Address>,
+    simulation_timestamp: Option<u64>,
+    simulation_base_fee: Option<u128>,
+    fee_strategy: Option<FeeStrategy>,
     phantom: PhantomData<N>,
 }
 
@@ -34,6 +55,15 @@ where
         Self {
             provider,
             bundle: EthSendBundle::default(),
+            target_blocks: None,
+            transactions: Vec::new(),
+            nonces: NonceTracker::default(),
+            chain_id: None,
+            state_block: None,
+            simulation_coinbase: None,
+            simulation_timestamp: None,
+            simulation_base_fee: None,
+            fee_strategy: None,
             phantom: PhantomData,
         }
     }
@@ -45,6 +75,69 @@ where
         self
     }
 
+    /// Adds a hex-encoded signed transaction, along with the way builders may
+    /// handle it. The transaction hash is added to the reverting or dropping
+    /// hashes accordingly.
+    pub fn add_signed_transaction_with_flags(self, tx: Bytes, flags: TransactionFlags) -> Self {
+        let hash = keccak256(&tx);
+        let mut builder = self.add_signed_transaction(tx);
+
+        if flags.can_revert {
+            builder = builder.add_reverting_tx(hash);
+        }
+
+        if flags.can_drop {
+            builder = builder.add_dropping_tx(hash);
+        }
+
+        builder
+    }
+
+    /// Decodes and validates a signed transaction, and then adds it to the
+    /// bundle.
+    ///
+    /// The transaction signer is recovered, and its chain ID, if any, must
+    /// match the provider's one. The decoded transaction is then available
+    /// using [`transactions`].
+    ///
+    /// [`transactions`]: EthBundleBuilder::transactions
+    pub async fn add_validated_transaction(mut self, tx: Bytes) -> TransportResult<Self> {
+        let decoded = BundleTransaction::decode(&tx)?;
+
+        let chain_id = match self.chain_id {
+            Some(chain_id) => chain_id,
+            None => *self.chain_id.insert(self.provider.get_chain_id().await?),
+        };
+
+        if let Some(tx_chain_id) = decoded.chain_id.filter(|id| *id != chain_id) {
+            return Err(TransportErrorKind::custom_str(&format!(
+                "transaction {} is for chain {tx_chain_id}, but the provider is on chain {chain_id}",
+                decoded.hash
+            )));
+        }
+
+        self.nonces.record(decoded.sender, decoded.nonce);
+        self.transactions.push(decoded);
+
+        Ok(self.add_signed_transaction(tx))
+    }
+
+    /// Returns the transactions decoded when added using
+    /// [`add_validated_transaction`] or a transaction request.
+    ///
+    /// [`add_validated_transaction`]: EthBundleBuilder::add_validated_transaction
+    pub fn transactions(&self) -> &[BundleTransaction] {
+        &self.transactions
+    }
+
+    /// Sets the [`NonceTracker`] used to assign nonces to the transaction
+    /// requests, e.g. to share it with other builders.
+    pub fn with_nonce_tracker(mut self, nonces: NonceTracker) -> Self {
+        self.nonces = nonces;
+
+        self
+    }
+
     /// Adds a hashes of a possibly reverting tx.
     pub fn add_reverting_tx(mut self, hash: B256) -> Self {
         self.bundle.reverting_tx_hashes.push(hash);
@@ -52,9 +145,37 @@ where
         self
     }
 
+    /// Adds a hashes of a tx that can be dropped from the bundle.
+    pub fn add_dropping_tx(mut self, hash: B256) -> Self {
+        self.bundle.dropping_tx_hashes.push(hash);
+
+        self
+    }
+
     /// Sets the block number for which this bundle is valid.
     pub const fn on_block(mut self, block: u64) -> Self {
         self.bundle.block_number = block;
+        self.target_blocks = None;
+
+        self
+    }
+
+    /// Sets the range of blocks this bundle targets. One bundle per block is
+    /// then built using [`build_for_blocks`].
+    ///
+    /// [`build_for_blocks`]: EthBundleBuilder::build_for_blocks
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, e.g. `10..=5`.
+    pub const fn on_blocks(mut self, blocks: RangeInclusive<u64>) -> Self {
+        assert!(
+            *blocks.start() <= *blocks.end(),
+            "the range of target blocks is empty"
+        );
+
+        self.bundle.block_number = *blocks.start();
+        self.target_blocks = Some(blocks);
 
         self
     }
@@ -80,10 +201,207 @@ where
         self
     }
 
+    /// Sets the percentage of the bundle profit refunded, between 0 and 99.
+    pub fn with_refund_percent(mut self, refund_percent: u8) -> TransportResult<Self> {
+        if refund_percent > 99 {
+            return Err(TransportErrorKind::custom_str(&format!(
+                "the refund percent must be between 0 and 99, got {refund_percent}"
+            )));
+        }
+
+        self.bundle.refund_percent = Some(refund_percent);
+
+        Ok(self)
+    }
+
+    /// Sets the address receiving the refund. By default, it's the sender of
+    /// the first transaction.
+    pub const fn with_refund_recipient(mut self, refund_recipient: Address) -> Self {
+        self.bundle.refund_recipient = Some(refund_recipient);
+
+        self
+    }
+
+    /// Adds the hash of a transaction whose profit is refunded. The
+    /// transaction must have been added to the bundle beforehand.
+    pub fn add_refund_tx(mut self, hash: B256) -> TransportResult<Self> {
+        if !self.bundle.txs.iter().any(|tx| keccak256(tx) == hash) {
+            return Err(TransportErrorKind::custom_str(&format!(
+                "transaction {hash} is not in the bundle"
+            )));
+        }
+
+        self.bundle.refund_tx_hashes.push(hash);
+
+        Ok(self)
+    }
+
+    /// Sets the block whose state the bundle is simulated on top of. By
+    /// default, it's the latest block, as the block preceding the bundle's
+    /// block isn't mined yet when targeting a later block than the next one.
+    pub const fn with_state_block(mut self, state_block: BlockNumberOrTag) -> Self {
+        self.state_block = Some(state_block);
+
+        self
+    }
+
+    /// Sets the coinbase of the block the bundle is simulated in.
+    pub const fn with_simulation_coinbase(mut self, coinbase: Address) -> Self {
+        self.simulation_coinbase = Some(coinbase);
+
+        self
+    }
+
+    /// Sets the timestamp of the block the bundle is simulated in.
+    pub const fn with_simulation_timestamp(mut self, timestamp: u64) -> Self {
+        self.simulation_timestamp = Some(timestamp);
+
+        self
+    }
+
+    /// Sets the base fee of the block the bundle is simulated in.
+    pub const fn with_simulation_base_fee(mut self, base_fee: u128) -> Self {
+        self.simulation_base_fee = Some(base_fee);
+
+        self
+    }
+
+    /// Sets how the fees of the transaction requests are priced, for the
+    /// target blocks rather than the next one.
+    ///
+    /// The target blocks are read when each transaction request is added, so
+    /// [`on_block`] or [`on_blocks`] must be called before
+    /// [`add_transaction_request`] and [`add_transaction_request_with_flags`].
+    ///
+    /// [`on_block`]: EthBundleBuilder::on_block
+    /// [`on_blocks`]: EthBundleBuilder::on_blocks
+    /// [`add_transaction_request`]: EthBundleBuilder::add_transaction_request
+    /// [`add_transaction_request_with_flags`]: EthBundleBuilder::add_transaction_request_with_flags
+    pub const fn with_fee_strategy(mut self, fee_strategy: FeeStrategy) -> Self {
+        self.fee_strategy = Some(fee_strategy);
+
+        self
+    }
+
+    /// Predicts the base fee of the last block targeted by the bundle, or of
+    /// the next block if none is targeted.
+    ///
+    /// See [`FeeStrategy::predict_base_fee`].
+    pub async fn predicted_base_fee(&self) -> TransportResult<u64> {
+        let parent = self
+            .provider
+            .get_block_by_number(BlockNumberOrTag::Latest)
+            .await?
+            .ok_or_else(|| TransportErrorKind::custom_str("the latest block is not available"))?;
+        let parent = parent.header();
+
+        let block_number = match &self.target_blocks {
+            Some(blocks) => *blocks.end(),
+            None => self.bundle.block_number,
+        };
+        let block_number = match block_number {
+            0 => parent.number() + 1,
+            block_number => block_number,
+        };
+
+        FeeStrategy::predict_base_fee(parent, block_number).ok_or_else(|| {
+            TransportErrorKind::custom_str(&format!(
+                "the base fee of block {block_number} can't be predicted"
+            ))
+        })
+    }
+
     /// Builds a [`EthSendBundle`].
     pub fn build(self) -> EthSendBundle {
         self.bundle
     }
+
+    /// Builds a [`EthCallBundle`] simulating the bundle being built, that
+    /// can be passed to [`EthMevProviderExt::call_eth_bundle`].
+    pub fn build_call_bundle(&self) -> EthCallBundle {
+        let block_number = self.bundle.block_number;
+
+        EthCallBundle {
+            txs: self.bundle.txs.clone(),
+            block_number,
+            state_block_number: self.state_block.unwrap_or(BlockNumberOrTag::Latest),
+            coinbase: self.simulation_coinbase,
+            timestamp: self.simulation_timestamp,
+            base_fee: self.simulation_base_fee,
+            ..Default::default()
+        }
+    }
+
+    /// Simulates the bundle using `eth_callBundle` on the given endpoints,
+    /// and then sends it to them only if every successful simulation meets
+    /// the given criteria.
+    ///
+    /// Endpoints failing to simulate the bundle, e.g. because they don't
+    /// support `eth_callBundle`, are ignored, as long as at least one of them
+    /// succeeds.
+    ///
+    /// Only the first target block is simulated and sent, even if a range of
+    /// blocks has been set using [`on_blocks`]: use [`build_for_blocks`]
+    /// and [`EthMevProviderExt::send_eth_bundles`] to target all of them.
+    ///
+    /// [`on_blocks`]: EthBundleBuilder::on_blocks
+    /// [`build_for_blocks`]: EthBundleBuilder::build_for_blocks
+    pub async fn simulate_then_send(
+        self,
+        endpoints: &Endpoints,
+        criteria: SimulationCriteria,
+    ) -> Result<Vec<BroadcastResponse<EthBundleHash>>, SimulationError> {
+        let simulations = self
+            .provider
+            .call_eth_bundle(self.build_call_bundle(), endpoints)
+            .await;
+
+        if !simulations.iter().any(BroadcastResponse::is_ok) {
+            return Err(SimulationError::Unavailable(simulations));
+        }
+
+        for simulation in &simulations {
+            if let Ok(response) = &simulation.result {
+                criteria.check(&self.bundle, &simulation.endpoint_name, response)?;
+            }
+        }
+
+        Ok(self.provider.send_eth_bundle(self.bundle, endpoints).await)
+    }
+
+    /// Sends the bundle to the given endpoints, and returns a
+    /// [`BundleHandle`] that can replace or cancel it.
+    ///
+    /// Unless one has been set, a replacement UUID is generated.
+    ///
+    /// Only the first target block is sent, even if a range of blocks has
+    /// been set using [`on_blocks`]: use [`build_for_blocks`] and
+    /// [`EthMevProviderExt::send_eth_bundles`] to target all of them.
+    ///
+    /// [`on_blocks`]: EthBundleBuilder::on_blocks
+    /// [`build_for_blocks`]: EthBundleBuilder::build_for_blocks
+    pub async fn send(self, endpoints: &'a Endpoints) -> BundleHandle<'a, P, N> {
+        BundleHandle::send(self.provider, self.bundle, endpoints).await
+    }
+
+    /// Builds one [`EthSendBundle`] for each of the blocks set using
+    /// [`on_blocks`], or a single one if only one block is targeted.
+    ///
+    /// If a replacement UUID has been set, it is shared by all the bundles,
+    /// so they can be cancelled at once.
+    ///
+    /// [`on_blocks`]: EthBundleBuilder::on_blocks
+    pub fn build_for_blocks(self) -> Vec<EthSendBundle> {
+        match self.target_blocks {
+            Some(blocks) => blocks
+                .map(|block_number| EthSendBundle {
+                    block_number,
+                    ..self.bundle.clone()
+                })
+                .collect(),
+            None => vec![self.bundle],
+        }
+    }
 }
 
 impl<'a, F, P, N> EthBundleBuilder<'a, FillProvider<F, P, N>, N>
@@ -94,11 +412,146 @@ where
 {
     /// Sign and encode a transaction request, and then add it to the bundle.
     pub async fn add_transaction_request(self, tx: N::TransactionRequest) -> TransportResult<Self> {
+        self.add_transaction_request_with_flags(tx, TransactionFlags::NONE)
+            .await
+    }
+
+    /// Sign and encode a transaction request, and then add it to the bundle,
+    /// along with the way builders may handle it.
+    ///
+    /// Unless the request has a nonce, the transactions of the same sender
+    /// get sequential nonces, starting from the one filled by the provider.
+    ///
+    /// If a [`FeeStrategy`] has been set, the fees of the request are priced
+    /// using it, unless they are already set.
+    ///
+    /// [`FeeStrategy`]: crate::FeeStrategy
+    pub async fn add_transaction_request_with_flags(
+        mut self,
+        mut tx: N::TransactionRequest,
+        flags: TransactionFlags,
+    ) -> TransportResult<Self> {
+        if let Some(fee_strategy) = self.fee_strategy {
+            if tx.max_fee_per_gas().is_none() && tx.gas_price().is_none() {
+                let (base_fee, priority_fee) = try_join!(
+                    self.predicted_base_fee(),
+                    fee_strategy.priority_fee(self.provider)
+                )?;
+
+                tx.set_max_priority_fee_per_gas(priority_fee);
+                tx.set_max_fee_per_gas(base_fee as u128 + priority_fee);
+            }
+        }
+
+        let reserved = match tx.nonce() {
+            Some(_) => None,
+            None => tx
+                .from()
+                .and_then(|from| Some((from, self.nonces.reserve(from)?))),
+        };
+
+        if let Some((_, nonce)) = reserved {
+            tx.set_nonce(nonce);
+        }
+
+        let (mut encoded, mut decoded) = self.sign_and_decode(tx.clone(), reserved).await?;
+
+        // The sender was unknown before signing, so the nonce may have to be
+        // replaced by the tracked one.
+        if tx.nonce().is_none() {
+            if let Some(nonce) = self.nonces.reserve(decoded.sender) {
+                if nonce != decoded.nonce {
+                    tx.set_from(decoded.sender);
+                    tx.set_nonce(nonce);
+
+                    (encoded, decoded) = self
+                        .sign_and_decode(tx, Some((decoded.sender, nonce)))
+                        .await?;
+                }
+            }
+        }
+
+        self.nonces.record(decoded.sender, decoded.nonce);
+        self.transactions.push(decoded);
+
+        Ok(self.add_signed_transaction_with_flags(encoded, flags))
+    }
+
+    /// Signs a transaction from the given sender paying the given amount to
+    /// the builder, and then adds it at the end of the bundle.
+    ///
+    /// A [`CoinbaseTip::transfer`] always pays the same address, whichever
+    /// builder includes the bundle: when sending it to several builders, use
+    /// a [`CoinbaseTip::call`] to a contract paying `block.coinbase`.
+    pub async fn add_coinbase_tip(
+        self,
+        from: Address,
+        tip: CoinbaseTip,
+        amount: U256,
+    ) -> TransportResult<Self> {
+        let tx = N::TransactionRequest::default()
+            .with_from(from)
+            .with_to(tip.to)
+            .with_value(amount)
+            .with_input(tip.input);
+
+        self.add_transaction_request(tx).await
+    }
+
+    /// Simulates the bundle built so far using `eth_callBundle` on the given
+    /// endpoints, and then adds a tip paying the amount derived from the
+    /// simulations, e.g. a share of the profit, using [`add_coinbase_tip`].
+    ///
+    /// [`add_coinbase_tip`]: EthBundleBuilder::add_coinbase_tip
+    pub async fn add_simulated_coinbase_tip(
+        self,
+        from: Address,
+        tip: CoinbaseTip,
+        endpoints: &Endpoints,
+        amount: impl FnOnce(&SimulationReport) -> U256,
+    ) -> TransportResult<Self> {
+        let report = SimulationReport::new(
+            self.provider
+                .call_eth_bundle(self.build_call_bundle(), endpoints)
+                .await,
+        );
+
+        if report.simulations.is_empty() {
+            return Err(TransportErrorKind::custom_str(
+                "the bundle can't be simulated to derive the coinbase tip",
+            ));
+        }
+
+        let amount = amount(&report);
+
+        self.add_coinbase_tip(from, tip, amount).await
+    }
+
+    /// Signs and decodes a transaction request, giving the reserved nonce
+    /// back to the tracker if it fails, so the sender's next transactions
+    /// don't have a nonce gap.
+    async fn sign_and_decode(
+        &self,
+        tx: N::TransactionRequest,
+        reserved: Option<(Address, u64)>,
+    ) -> TransportResult<(Bytes, BundleTransaction)> {
+        let signed = match self.sign(tx).await {
+            Ok(encoded) => BundleTransaction::decode(&encoded).map(|decoded| (encoded, decoded)),
+            Err(err) => Err(err),
+        };
+
+        if let (Err(_), Some((sender, nonce))) = (&signed, reserved) {
+            self.nonces.release(sender, nonce);
+        }
+
+        signed
+    }
+
+    async fn sign(&self, tx: N::TransactionRequest) -> TransportResult<Bytes> {
         let sendable = self.provider.fill(tx).await?;
 
         if let Some(envelope) = sendable.as_envelope() {
-            let encoded = envelope.encoded_2718().into();
-            Ok(self.add_signed_transaction(encoded))
+            Ok(envelope.encoded_2718().into())
         } else {
             Err(TransportErrorKind::custom_str("No signer has been setup"))
         }
@@ -0,0 +1,175 @@
+use std::marker::PhantomData;
+
+use alloy::{
+    network::Network,
+    providers::Provider,
+    rpc::types::mev::{EthBundleHash, EthCancelBundle, EthSendBundle},
+};
+use uuid::Uuid;
+
+use super::{BroadcastResponse, Endpoints, EthMevProviderExt};
+
+/// An operation performed on a bundle through a [`BundleHandle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BundleOperation {
+    /// The bundle has been sent for the first time.
+    Send,
+    /// The bundle has been replaced by a new one.
+    Replace,
+    /// The bundle has been cancelled.
+    Cancel,
+}
+
+/// The endpoints that acknowledged, or not, an operation on a bundle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleAcknowledgement {
+    /// The operation performed.
+    pub operation: BundleOperation,
+    /// The names of the endpoints that acknowledged the operation.
+    pub acknowledged: Vec<String>,
+    /// The names of the endpoints that failed to acknowledge the operation.
+    pub rejected: Vec<String>,
+}
+
+impl BundleAcknowledgement {
+    fn new<Resp>(operation: BundleOperation, responses: &[BroadcastResponse<Resp>]) -> Self {
+        let (acknowledged, rejected): (Vec<_>, Vec<_>) =
+            responses.iter().partition(|response| response.is_ok());
+
+        Self {
+            operation,
+            acknowledged: names(acknowledged),
+            rejected: names(rejected),
+        }
+    }
+
+    /// Returns `true` if every endpoint acknowledged the operation.
+    pub const fn is_complete(&self) -> bool {
+        self.rejected.is_empty()
+    }
+}
+
+/// A bundle sent to one or more builder(s), that can then be replaced or
+/// cancelled using its replacement UUID, on the same endpoints.
+///
+/// It's returned by [`EthBundleBuilder::send`].
+///
+/// [`EthBundleBuilder::send`]: crate::EthBundleBuilder::send
+#[derive(Debug)]
+pub struct BundleHandle<'a, P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    provider: &'a P,
+    endpoints: &'a Endpoints,
+    bundle: EthSendBundle,
+    replacement_uuid: String,
+    responses: Vec<BroadcastResponse<EthBundleHash>>,
+    history: Vec<BundleAcknowledgement>,
+    phantom: PhantomData<N>,
+}
+
+impl<'a, P, N> BundleHandle<'a, P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    /// Sends the given bundle to the given endpoints, using its replacement
+    /// UUID if it has one, or a newly generated one otherwise.
+    pub async fn send(
+        provider: &'a P,
+        mut bundle: EthSendBundle,
+        endpoints: &'a Endpoints,
+    ) -> Self {
+        let replacement_uuid = bundle
+            .replacement_uuid
+            .get_or_insert_with(|| Uuid::new_v4().to_string())
+            .clone();
+
+        let mut handle = Self {
+            provider,
+            endpoints,
+            bundle: EthSendBundle::default(),
+            replacement_uuid,
+            responses: Vec::new(),
+            history: Vec::new(),
+            phantom: PhantomData,
+        };
+
+        handle.submit(bundle, BundleOperation::Send).await;
+
+        handle
+    }
+
+    /// Returns the replacement UUID of the bundle.
+    pub fn replacement_uuid(&self) -> &str {
+        &self.replacement_uuid
+    }
+
+    /// Returns the last bundle sent.
+    pub const fn bundle(&self) -> &EthSendBundle {
+        &self.bundle
+    }
+
+    /// Returns the responses of the endpoints to the last bundle sent.
+    pub fn responses(&self) -> &[BroadcastResponse<EthBundleHash>] {
+        &self.responses
+    }
+
+    /// Returns the endpoints that acknowledged each operation, in order.
+    pub fn history(&self) -> &[BundleAcknowledgement] {
+        &self.history
+    }
+
+    /// Replaces the bundle by the given one, that gets the same replacement
+    /// UUID.
+    pub async fn replace(&mut self, bundle: EthSendBundle) -> &BundleAcknowledgement {
+        self.submit(bundle, BundleOperation::Replace).await
+    }
+
+    /// Cancels the bundle using `eth_cancelBundle`.
+    pub async fn cancel(&mut self) -> &BundleAcknowledgement {
+        let request = EthCancelBundle {
+            replacement_uuid: self.replacement_uuid.clone(),
+        };
+        let responses = self
+            .provider
+            .cancel_eth_bundle_to(request, self.endpoints)
+            .await;
+
+        self.record(BundleAcknowledgement::new(
+            BundleOperation::Cancel,
+            &responses,
+        ))
+    }
+
+    async fn submit(
+        &mut self,
+        mut bundle: EthSendBundle,
+        operation: BundleOperation,
+    ) -> &BundleAcknowledgement {
+        bundle.replacement_uuid = Some(self.replacement_uuid.clone());
+
+        self.responses = self
+            .provider
+            .send_eth_bundle(bundle.clone(), self.endpoints)
+            .await;
+        self.bundle = bundle;
+
+        self.record(BundleAcknowledgement::new(operation, &self.responses))
+    }
+
+    fn record(&mut self, acknowledgement: BundleAcknowledgement) -> &BundleAcknowledgement {
+        self.history.push(acknowledgement);
+
+        &self.history[self.history.len() - 1]
+    }
+}
+
+fn names<Resp>(responses: Vec<&BroadcastResponse<Resp>>) -> Vec<String> {
+    responses
+        .into_iter()
+        .map(|response| response.endpoint_name.clone())
+        .collect()
+}
@@ -0,0 +1,515 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt::{self, Display},
+    future::IntoFuture,
+    marker::PhantomData,
+};
+
+use alloy::{
+    consensus::{
+        transaction::{Recovered, SignerRecoverable},
+        BlockHeader, Transaction, TxEnvelope,
+    },
+    eips::{eip1559::BaseFeeParams, BlockId, BlockNumberOrTag, Decodable2718, Typed2718},
+    network::{primitives::HeaderResponse, BlockResponse, Network, TransactionBuilder},
+    primitives::{keccak256, Address, Bytes, B256, U256},
+    providers::Provider,
+    rpc::types::mev::{EthCallBundleResponse, EthCallBundleTransactionResult, EthSendBundle},
+    transports::{TransportErrorKind, TransportResult},
+};
+use futures::{
+    future::{join_all, try_join_all},
+    try_join,
+};
+use revm::{
+    context::{
+        result::{EVMError, ExecResultAndState, ExecutionResult},
+        BlockEnv, CfgEnv, TxEnv,
+    },
+    database::CacheDB,
+    database_interface::DBErrorMarker,
+    primitives::hardfork::SpecId,
+    state::{AccountInfo, Bytecode},
+    Context, DatabaseRef, ExecuteCommitEvm, ExecuteEvm, MainBuilder, MainContext,
+};
+
+use crate::utils::per_gas;
+
+/// Simulates a bundle locally, using [`revm`] on top of the state of a
+/// block, fetched from the provider.
+///
+/// The state the bundle is expected to access, according to
+/// `eth_createAccessList`, is fetched concurrently before the execution. Any
+/// state still missing is then fetched when the execution needs it, and the
+/// bundle re-executed.
+///
+/// The bundle is executed in a new block built on top of the state block,
+/// and the returned response is shaped like the one of `eth_callBundle`, so
+/// bundles can be validated without depending on a relay.
+#[derive(Debug)]
+pub struct BundleSimulator<'a, P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    provider: &'a P,
+    txs: Vec<SimulatedTransaction>,
+    block_number: Option<u64>,
+    state_block: BlockNumberOrTag,
+    coinbase: Option<Address>,
+    timestamp: Option<u64>,
+    base_fee: Option<u64>,
+    spec: SpecId,
+    phantom: PhantomData<N>,
+}
+
+#[derive(Debug)]
+struct SimulatedTransaction {
+    tx: Recovered<TxEnvelope>,
+    env: TxEnv,
+}
+
+impl<'a, P, N> BundleSimulator<'a, P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    /// Creates a new [`BundleSimulator`] for the given bundle, executed on
+    /// top of the latest block, as the block preceding the bundle's block
+    /// isn't mined yet when targeting a later block than the next one.
+    pub fn new(provider: &'a P, bundle: &EthSendBundle) -> TransportResult<Self> {
+        let txs = bundle
+            .txs
+            .iter()
+            .map(|tx| SimulatedTransaction::decode(tx))
+            .collect::<TransportResult<Vec<_>>>()?;
+
+        Ok(Self {
+            provider,
+            txs,
+            block_number: (bundle.block_number != 0).then_some(bundle.block_number),
+            state_block: BlockNumberOrTag::Latest,
+            coinbase: None,
+            timestamp: None,
+            base_fee: None,
+            spec: SpecId::default(),
+            phantom: PhantomData,
+        })
+    }
+
+    /// Sets the block whose state the bundle is executed on top of.
+    pub const fn with_state_block(mut self, state_block: BlockNumberOrTag) -> Self {
+        self.state_block = state_block;
+
+        self
+    }
+
+    /// Sets the fee recipient of the simulated block. By default, it's the
+    /// one of the state block.
+    pub const fn with_coinbase(mut self, coinbase: Address) -> Self {
+        self.coinbase = Some(coinbase);
+
+        self
+    }
+
+    /// Sets the timestamp of the simulated block. By default, it's 12
+    /// seconds after the state block.
+    pub const fn with_timestamp(mut self, timestamp: u64) -> Self {
+        self.timestamp = Some(timestamp);
+
+        self
+    }
+
+    /// Sets the base fee of the simulated block. By default, it's derived
+    /// from the state block.
+    pub const fn with_base_fee(mut self, base_fee: u64) -> Self {
+        self.base_fee = Some(base_fee);
+
+        self
+    }
+
+    /// Sets the hard fork the bundle is executed with. Defaults to the latest
+    /// one supported by [`revm`].
+    pub const fn with_spec(mut self, spec: SpecId) -> Self {
+        self.spec = spec;
+
+        self
+    }
+
+    /// Executes the bundle transactions in order.
+    ///
+    /// The `coinbase_diff` of each transaction is the fee recipient balance
+    /// change, and its `gas_price` is this difference per unit of gas used.
+    pub async fn simulate(&self) -> TransportResult<EthCallBundleResponse> {
+        let state_block = self
+            .provider
+            .get_block_by_number(self.state_block)
+            .await?
+            .ok_or_else(|| TransportErrorKind::custom_str("the state block is not available"))?;
+        let state_block_number = state_block.header().number();
+        let state_block_id = BlockId::from(state_block_number);
+        let block = self.block_env(state_block.header());
+        let cfg =
+            CfgEnv::new_with_spec(self.spec).with_chain_id(self.provider.get_chain_id().await?);
+        let mut state = ForkState::default();
+
+        let keys = self.expected_state(state_block_id, block.beneficiary).await;
+        state.fetch(self.provider, state_block_id, keys).await?;
+
+        loop {
+            match self.execute(&state, &block, &cfg) {
+                Ok(results) => return Ok(bundle_response(results, state_block_number)),
+                Err(EVMError::Database(MissingState(key))) => {
+                    state.fetch(self.provider, state_block_id, [key]).await?
+                }
+                Err(err) => return Err(TransportErrorKind::custom(err)),
+            }
+        }
+    }
+
+    /// Returns the state the bundle is expected to access: the coinbase, the
+    /// senders and recipients, and the accounts and storage slots of the
+    /// transactions access lists.
+    ///
+    /// The access lists are created by the provider, each transaction being
+    /// executed on its own on top of the state block. They are only a hint,
+    /// so providers failing to create them are ignored.
+    async fn expected_state(&self, block: BlockId, coinbase: Address) -> Vec<StateKey> {
+        let created_access_lists = join_all(self.txs.iter().map(|tx| async move {
+            let request = tx.request::<N>();
+
+            self.provider
+                .create_access_list(&request)
+                .block_id(block)
+                .await
+                .map(|result| result.access_list)
+                .ok()
+        }))
+        .await;
+
+        let mut keys = vec![StateKey::Account(coinbase)];
+
+        for SimulatedTransaction { tx, .. } in &self.txs {
+            keys.push(StateKey::Account(tx.signer()));
+            keys.extend(tx.to().map(StateKey::Account));
+        }
+
+        let access_lists = self
+            .txs
+            .iter()
+            .filter_map(|simulated| simulated.tx.access_list().cloned())
+            .chain(created_access_lists.into_iter().flatten());
+
+        for item in access_lists.flat_map(|access_list| access_list.0) {
+            keys.push(StateKey::Account(item.address));
+            keys.extend(
+                item.storage_keys
+                    .into_iter()
+                    .map(|key| StateKey::Storage(item.address, key.into())),
+            );
+        }
+
+        let mut seen = HashSet::new();
+        keys.retain(|key| seen.insert(*key));
+
+        keys
+    }
+
+    fn block_env(&self, parent: &N::HeaderResponse) -> BlockEnv {
+        BlockEnv {
+            number: U256::from(self.block_number.unwrap_or(parent.number() + 1)),
+            beneficiary: self.coinbase.unwrap_or(parent.beneficiary()),
+            timestamp: U256::from(self.timestamp.unwrap_or(parent.timestamp() + 12)),
+            gas_limit: parent.gas_limit(),
+            basefee: self.base_fee.unwrap_or_else(|| {
+                parent
+                    .next_block_base_fee(BaseFeeParams::ethereum())
+                    .unwrap_or_default()
+            }),
+            prevrandao: Some(parent.mix_hash().unwrap_or_default()),
+            ..Default::default()
+        }
+    }
+
+    /// Executes the bundle against the state fetched so far, failing with
+    /// the first missing piece of state, if any.
+    fn execute(
+        &self,
+        state: &ForkState,
+        block: &BlockEnv,
+        cfg: &CfgEnv,
+    ) -> Result<Vec<EthCallBundleTransactionResult>, EVMError<MissingState>> {
+        let coinbase = block.beneficiary;
+        let mut coinbase_balance = state
+            .basic_ref(coinbase)
+            .map_err(EVMError::Database)?
+            .map(|account| account.balance)
+            .unwrap_or_default();
+
+        let mut evm = Context::mainnet()
+            .with_db(CacheDB::new(state))
+            .with_block(block.clone())
+            .with_cfg(cfg.clone())
+            .build_mainnet();
+
+        let mut results = Vec::with_capacity(self.txs.len());
+
+        for SimulatedTransaction { tx, env } in &self.txs {
+            let ExecResultAndState { result, state } = evm.transact(env.clone())?;
+
+            let balance = state
+                .get(&coinbase)
+                .map_or(coinbase_balance, |account| account.info.balance);
+            evm.commit(state);
+
+            let coinbase_diff = balance.saturating_sub(coinbase_balance);
+            coinbase_balance = balance;
+
+            let gas_used = result.tx_gas_used();
+            let tip = tx.effective_tip_per_gas(block.basefee).unwrap_or_default();
+            let gas_fees = U256::from(tip) * U256::from(gas_used);
+
+            let (value, revert) = match result {
+                ExecutionResult::Success { output, .. } => (Some(output.into_data()), None),
+                ExecutionResult::Revert { output, .. } => (None, Some(output)),
+                ExecutionResult::Halt { .. } => (None, Some(Bytes::new())),
+            };
+
+            results.push(EthCallBundleTransactionResult {
+                coinbase_diff,
+                eth_sent_to_coinbase: coinbase_diff.saturating_sub(gas_fees),
+                from_address: tx.signer(),
+                gas_fees,
+                gas_price: per_gas(coinbase_diff, gas_used),
+                gas_used,
+                to_address: tx.to(),
+                tx_hash: *tx.tx_hash(),
+                value,
+                revert,
+            });
+        }
+
+        Ok(results)
+    }
+}
+
+impl SimulatedTransaction {
+    fn request<N: Network>(&self) -> N::TransactionRequest {
+        N::TransactionRequest::default()
+            .with_from(self.tx.signer())
+            .with_kind(self.tx.kind())
+            .with_value(self.tx.value())
+            .with_input(self.tx.input().clone())
+            .with_gas_limit(self.tx.gas_limit())
+    }
+
+    fn decode(tx: &[u8]) -> TransportResult<Self> {
+        let tx = TxEnvelope::decode_2718_exact(tx)
+            .map_err(TransportErrorKind::custom)?
+            .try_into_recovered()
+            .map_err(TransportErrorKind::custom)?;
+
+        let env = TxEnv::builder()
+            .tx_type(Some(tx.ty()))
+            .caller(tx.signer())
+            .gas_limit(tx.gas_limit())
+            .gas_price(tx.max_fee_per_gas())
+            .gas_priority_fee(tx.max_priority_fee_per_gas())
+            .kind(tx.kind())
+            .value(tx.value())
+            .data(tx.input().clone())
+            .nonce(tx.nonce())
+            .chain_id(tx.chain_id())
+            .access_list(tx.access_list().cloned().unwrap_or_default())
+            .blob_hashes(tx.blob_versioned_hashes().unwrap_or_default().to_vec())
+            .max_fee_per_blob_gas(tx.max_fee_per_blob_gas().unwrap_or_default())
+            .authorization_list_signed(tx.authorization_list().unwrap_or_default().to_vec())
+            .build()
+            .map_err(TransportErrorKind::custom)?;
+
+        Ok(Self { tx, env })
+    }
+}
+
+fn bundle_response(
+    results: Vec<EthCallBundleTransactionResult>,
+    state_block_number: u64,
+) -> EthCallBundleResponse {
+    let hashes = results
+        .iter()
+        .flat_map(|result| result.tx_hash.0)
+        .collect::<Vec<_>>();
+    let total_gas_used = results.iter().map(|result| result.gas_used).sum();
+    let coinbase_diff = results.iter().map(|result| result.coinbase_diff).sum();
+
+    EthCallBundleResponse {
+        bundle_hash: keccak256(hashes),
+        bundle_gas_price: per_gas(coinbase_diff, total_gas_used),
+        coinbase_diff,
+        eth_sent_to_coinbase: results.iter().map(|r| r.eth_sent_to_coinbase).sum(),
+        gas_fees: results.iter().map(|r| r.gas_fees).sum(),
+        results,
+        state_block_number,
+        total_gas_used,
+    }
+}
+
+/// A piece of state the simulation needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum StateKey {
+    Account(Address),
+    Storage(Address, U256),
+    BlockHash(u64),
+}
+
+/// The state fetched so far from the provider.
+#[derive(Debug, Default)]
+struct ForkState {
+    accounts: HashMap<Address, AccountInfo>,
+    storage: HashMap<(Address, U256), U256>,
+    block_hashes: HashMap<u64, B256>,
+}
+
+impl ForkState {
+    /// Fetches concurrently the given pieces of state not fetched yet.
+    async fn fetch<P, N>(
+        &mut self,
+        provider: &P,
+        block: BlockId,
+        keys: impl IntoIterator<Item = StateKey>,
+    ) -> TransportResult<()>
+    where
+        P: Provider<N>,
+        N: Network,
+    {
+        let keys = keys.into_iter().filter(|key| !self.contains(key));
+        let values = try_join_all(keys.map(|key| fetch_state(provider, block, key))).await?;
+
+        for value in values {
+            match value {
+                StateValue::Account(address, account) => {
+                    self.accounts.insert(address, account);
+                }
+                StateValue::Storage(address, index, value) => {
+                    self.storage.insert((address, index), value);
+                }
+                StateValue::BlockHash(number, hash) => {
+                    self.block_hashes.insert(number, hash);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn contains(&self, key: &StateKey) -> bool {
+        match *key {
+            StateKey::Account(address) => self.accounts.contains_key(&address),
+            StateKey::Storage(address, index) => self.storage.contains_key(&(address, index)),
+            StateKey::BlockHash(number) => self.block_hashes.contains_key(&number),
+        }
+    }
+}
+
+/// A piece of state fetched from the provider.
+#[derive(Debug)]
+enum StateValue {
+    Account(Address, AccountInfo),
+    Storage(Address, U256, U256),
+    BlockHash(u64, B256),
+}
+
+async fn fetch_state<P, N>(
+    provider: &P,
+    block: BlockId,
+    key: StateKey,
+) -> TransportResult<StateValue>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    match key {
+        StateKey::Account(address) => {
+            let (nonce, balance, code) = try_join!(
+                provider
+                    .get_transaction_count(address)
+                    .block_id(block)
+                    .into_future(),
+                provider.get_balance(address).block_id(block).into_future(),
+                provider.get_code_at(address).block_id(block).into_future(),
+            )?;
+            let code = Bytecode::new_raw(code);
+
+            Ok(StateValue::Account(
+                address,
+                AccountInfo::new(balance, nonce, code.hash_slow(), code),
+            ))
+        }
+        StateKey::Storage(address, index) => {
+            let value = provider
+                .get_storage_at(address, index)
+                .block_id(block)
+                .await?;
+
+            Ok(StateValue::Storage(address, index, value))
+        }
+        StateKey::BlockHash(number) => {
+            let block = provider
+                .get_block_by_number(number.into())
+                .await?
+                .ok_or_else(|| TransportErrorKind::custom_str("the block is not available"))?;
+
+            Ok(StateValue::BlockHash(number, block.header().hash()))
+        }
+    }
+}
+
+impl DatabaseRef for ForkState {
+    type Error = MissingState;
+
+    fn basic_ref(&self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        match self.accounts.get(&address) {
+            Some(account) => Ok(Some(account.clone())),
+            None => Err(MissingState(StateKey::Account(address))),
+        }
+    }
+
+    fn code_by_hash_ref(&self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        // The code is always fetched along with the account.
+        Ok(self
+            .accounts
+            .values()
+            .find(|account| account.code_hash == code_hash)
+            .and_then(|account| account.code.clone())
+            .unwrap_or_default())
+    }
+
+    fn storage_ref(&self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        match self.storage.get(&(address, index)) {
+            Some(value) => Ok(*value),
+            None => Err(MissingState(StateKey::Storage(address, index))),
+        }
+    }
+
+    fn block_hash_ref(&self, number: u64) -> Result<B256, Self::Error> {
+        match self.block_hashes.get(&number) {
+            Some(hash) => Ok(*hash),
+            None => Err(MissingState(StateKey::BlockHash(number))),
+        }
+    }
+}
+
+/// The error aborting an execution when some state hasn't been fetched yet.
+#[derive(Debug)]
+struct MissingState(StateKey);
+
+impl Display for MissingState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing state: {:?}", self.0)
+    }
+}
+
+impl Error for MissingState {}
+
+impl DBErrorMarker for MissingState {}
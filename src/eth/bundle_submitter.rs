@@ -0,0 +1,192 @@
+use std::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    time::Duration,
+};
+
+use alloy::{
+    network::Network,
+    providers::Provider,
+    rpc::types::mev::{EthBundleHash, EthSendBundle},
+    transports::{TransportErrorKind, TransportResult},
+};
+use futures::StreamExt;
+
+use super::{BroadcastResponse, BundleTracker, Endpoints, EthMevProviderExt, InclusionStatus};
+
+/// The final outcome of a [`BundleSubmitter`].
+#[derive(Debug)]
+pub enum SubmissionOutcome {
+    /// A transaction of the bundle has been mined, or a transaction nonce
+    /// has been consumed by another transaction.
+    Settled(InclusionStatus),
+    /// The last block the bundle could be included in has been mined
+    /// without including it.
+    Expired {
+        /// The last block the bundle has been submitted for.
+        last_block: u64,
+        /// The responses of the builders to the last submission, empty if
+        /// the bundle has never been submitted.
+        responses: Vec<BroadcastResponse<EthBundleHash>>,
+    },
+    /// Every builder answered the submission with an error, e.g. because
+    /// the bundle is malformed, so it's not submitted anymore.
+    Rejected {
+        /// The block the bundle has been submitted for.
+        block_number: u64,
+        /// The responses of the builders.
+        responses: Vec<BroadcastResponse<EthBundleHash>>,
+    },
+}
+
+type SubmissionCallback<'a> = Box<dyn FnMut(u64, &[BroadcastResponse<EthBundleHash>]) + Send + 'a>;
+
+/// Resends a bundle to the builders on each new block, until it lands or
+/// can't be included anymore.
+pub struct BundleSubmitter<'a, P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    provider: &'a P,
+    endpoints: &'a Endpoints,
+    bundle: EthSendBundle,
+    max_block: Option<u64>,
+    timeout: Option<Duration>,
+    on_submission: Option<SubmissionCallback<'a>>,
+    phantom: PhantomData<N>,
+}
+
+impl<P, N> Debug for BundleSubmitter<'_, P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BundleSubmitter")
+            .field("endpoints", &self.endpoints)
+            .field("bundle", &self.bundle)
+            .field("max_block", &self.max_block)
+            .field("timeout", &self.timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, P, N> BundleSubmitter<'a, P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    /// Creates a new [`BundleSubmitter`].
+    pub const fn new(provider: &'a P, bundle: EthSendBundle, endpoints: &'a Endpoints) -> Self {
+        Self {
+            provider,
+            endpoints,
+            bundle,
+            max_block: None,
+            timeout: None,
+            on_submission: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Sets the last block the bundle is submitted for. By default, the
+    /// bundle is submitted until it lands or one of its nonces is consumed.
+    pub const fn until_block(mut self, max_block: u64) -> Self {
+        self.max_block = Some(max_block);
+
+        self
+    }
+
+    /// Sets the maximum duration each builder has to answer, for each
+    /// submission.
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Sets a callback called with the responses of the builders after each
+    /// submission, along with the block the bundle has been submitted for.
+    pub fn on_submission(
+        mut self,
+        callback: impl FnMut(u64, &[BroadcastResponse<EthBundleHash>]) + Send + 'a,
+    ) -> Self {
+        self.on_submission = Some(Box::new(callback));
+
+        self
+    }
+
+    /// Submits the bundle for the next block, and then again each time a new
+    /// block is mined, until the bundle lands or expires.
+    ///
+    /// The submission stops as soon as every builder answers with an error.
+    /// The builders failing to answer, e.g. because of a timeout, are
+    /// submitted the bundle again on the next block. Returns an error if
+    /// there are no endpoints to submit the bundle to.
+    pub async fn run(mut self) -> TransportResult<SubmissionOutcome> {
+        if self.endpoints.is_empty() {
+            return Err(TransportErrorKind::custom_str(
+                "there are no endpoints to submit the bundle to",
+            ));
+        }
+
+        let mut blocks = self.provider.watch_blocks().await?.into_stream();
+        let mut block_number = self.provider.get_block_number().await?;
+        let tracker = BundleTracker::new(self.provider, &self.bundle)?
+            .on_blocks(block_number + 1..=self.max_block.unwrap_or(u64::MAX));
+        let mut responses = Vec::new();
+
+        loop {
+            let status = tracker.status().await?;
+
+            if status != InclusionStatus::NotIncluded {
+                return Ok(SubmissionOutcome::Settled(status));
+            }
+
+            if let Some(max_block) = self.max_block.filter(|max| block_number >= *max) {
+                return Ok(SubmissionOutcome::Expired {
+                    last_block: max_block,
+                    responses,
+                });
+            }
+
+            responses = self.submit(block_number + 1).await;
+
+            if responses.iter().all(BroadcastResponse::is_rejected) {
+                return Ok(SubmissionOutcome::Rejected {
+                    block_number: block_number + 1,
+                    responses,
+                });
+            }
+
+            if blocks.next().await.is_none() {
+                return Ok(SubmissionOutcome::Expired {
+                    last_block: block_number + 1,
+                    responses,
+                });
+            }
+
+            block_number = self.provider.get_block_number().await?;
+        }
+    }
+
+    async fn submit(&mut self, block_number: u64) -> Vec<BroadcastResponse<EthBundleHash>> {
+        self.bundle.block_number = block_number;
+
+        let call = self
+            .provider
+            .send_eth_bundle(self.bundle.clone(), self.endpoints);
+
+        let responses = match self.timeout {
+            Some(timeout) => call.with_timeout(timeout).await,
+            None => call.await,
+        };
+
+        if let Some(callback) = &mut self.on_submission {
+            callback(block_number, &responses);
+        }
+
+        responses
+    }
+}
@@ -0,0 +1,208 @@
+use std::{marker::PhantomData, ops::RangeInclusive};
+
+use alloy::{
+    consensus::BlockHeader,
+    network::{BlockResponse, Network, ReceiptResponse},
+    primitives::{Address, Bytes, TxHash},
+    providers::Provider,
+    rpc::types::{mev::EthSendBundle, BlockNumberOrTag},
+    transports::{TransportErrorKind, TransportResult},
+};
+use futures::StreamExt;
+
+use super::BundleTransaction;
+
+/// The block a bundle, or part of it, has been included in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncludingBlock {
+    /// The block number.
+    pub number: u64,
+    /// The fee recipient of the block, usually the builder that built it.
+    pub coinbase: Address,
+    /// The extra data of the block, usually identifying the builder that
+    /// built it.
+    pub extra_data: Bytes,
+}
+
+/// Whether a bundle has landed on chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InclusionStatus {
+    /// All the transactions of the bundle have been included in the block.
+    Included(IncludingBlock),
+    /// Some transactions of the bundle have been included in the block, and
+    /// the other ones have not been mined.
+    PartiallyIncluded {
+        /// The block including the transactions.
+        block: IncludingBlock,
+        /// The hashes of the included transactions.
+        included: Vec<TxHash>,
+        /// The hashes of the missing transactions.
+        missing: Vec<TxHash>,
+    },
+    /// Some transactions of the bundle have been mined, but not together in
+    /// one of the target blocks, e.g. because they have been sent on their
+    /// own through the public mempool.
+    MinedOutsideBundle {
+        /// The hashes of the mined transactions, along with their block
+        /// number.
+        mined: Vec<(TxHash, u64)>,
+        /// The hashes of the transactions that have not been mined.
+        missing: Vec<TxHash>,
+    },
+    /// A transaction nonce has been consumed by another transaction, so the
+    /// bundle can't be included anymore.
+    FrontRun {
+        /// The hash of the bundle transaction whose nonce has been consumed.
+        tx_hash: TxHash,
+        /// The sender of the transaction.
+        sender: Address,
+        /// The consumed nonce.
+        nonce: u64,
+    },
+    /// None of the transactions of the bundle have been mined.
+    NotIncluded,
+}
+
+impl InclusionStatus {
+    /// Returns `true` if at least one transaction of the bundle has been
+    /// included.
+    pub const fn is_included(&self) -> bool {
+        matches!(self, Self::Included(_) | Self::PartiallyIncluded { .. })
+    }
+}
+
+/// Tracks whether a bundle has landed on chain.
+#[derive(Debug)]
+pub struct BundleTracker<'a, P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    provider: &'a P,
+    txs: Vec<BundleTransaction>,
+    target_blocks: RangeInclusive<u64>,
+    phantom: PhantomData<N>,
+}
+
+impl<'a, P, N> BundleTracker<'a, P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    /// Creates a new [`BundleTracker`] for the given bundle, that targets the
+    /// bundle's block.
+    pub fn new(provider: &'a P, bundle: &EthSendBundle) -> TransportResult<Self> {
+        let txs = bundle
+            .txs
+            .iter()
+            .map(|tx| BundleTransaction::decode(tx))
+            .collect::<TransportResult<Vec<_>>>()?;
+
+        Ok(Self {
+            provider,
+            txs,
+            target_blocks: bundle.block_number..=bundle.block_number,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Sets the blocks the bundle has been submitted for, e.g. when sent
+    /// using [`EthBundleBuilder::on_blocks`].
+    ///
+    /// [`EthBundleBuilder::on_blocks`]: crate::EthBundleBuilder::on_blocks
+    pub const fn on_blocks(mut self, blocks: RangeInclusive<u64>) -> Self {
+        self.target_blocks = blocks;
+
+        self
+    }
+
+    /// Returns the decoded transactions of the tracked bundle.
+    pub fn transactions(&self) -> &[BundleTransaction] {
+        &self.txs
+    }
+
+    /// Waits for the last target block to be mined, and then returns the
+    /// inclusion status of the bundle.
+    pub async fn wait(&self) -> TransportResult<InclusionStatus> {
+        let last_block = *self.target_blocks.end();
+        let mut blocks = self.provider.watch_blocks().await?.into_stream();
+
+        while self.provider.get_block_number().await? < last_block {
+            if blocks.next().await.is_none() {
+                return Err(TransportErrorKind::custom_str("the block stream has ended"));
+            }
+        }
+
+        self.status().await
+    }
+
+    /// Returns the current inclusion status of the bundle.
+    ///
+    /// The bundle is only considered included if all its mined transactions
+    /// share the same block, within the target blocks.
+    pub async fn status(&self) -> TransportResult<InclusionStatus> {
+        let mut mined = Vec::new();
+        let mut missing = Vec::new();
+
+        for tx in &self.txs {
+            let receipt = self.provider.get_transaction_receipt(tx.hash).await?;
+
+            match receipt.and_then(|r| r.block_number()) {
+                Some(number) => mined.push((tx.hash, number)),
+                None => missing.push(tx.hash),
+            }
+        }
+
+        if let Some(&(_, block_number)) = mined.first() {
+            let bundled = self.target_blocks.contains(&block_number)
+                && mined.iter().all(|(_, number)| *number == block_number);
+
+            if !bundled {
+                return Ok(InclusionStatus::MinedOutsideBundle { mined, missing });
+            }
+
+            let block = self.including_block(block_number).await?;
+
+            return Ok(if missing.is_empty() {
+                InclusionStatus::Included(block)
+            } else {
+                InclusionStatus::PartiallyIncluded {
+                    block,
+                    included: mined.into_iter().map(|(hash, _)| hash).collect(),
+                    missing,
+                }
+            });
+        }
+
+        for tx in &self.txs {
+            let nonce = self.provider.get_transaction_count(tx.sender).await?;
+
+            if nonce > tx.nonce {
+                return Ok(InclusionStatus::FrontRun {
+                    tx_hash: tx.hash,
+                    sender: tx.sender,
+                    nonce: tx.nonce,
+                });
+            }
+        }
+
+        Ok(InclusionStatus::NotIncluded)
+    }
+
+    async fn including_block(&self, number: u64) -> TransportResult<IncludingBlock> {
+        let block = self
+            .provider
+            .get_block_by_number(BlockNumberOrTag::Number(number))
+            .await?
+            .ok_or_else(|| {
+                TransportErrorKind::custom_str("the including block is not available")
+            })?;
+        let header = block.header();
+
+        Ok(IncludingBlock {
+            number,
+            coinbase: header.beneficiary(),
+            extra_data: header.extra_data().clone(),
+        })
+    }
+}
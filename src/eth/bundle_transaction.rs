@@ -0,0 +1,36 @@
+use alloy::{
+    consensus::{transaction::SignerRecoverable, Transaction, TxEnvelope},
+    eips::Decodable2718,
+    primitives::{Address, TxHash},
+    transports::{TransportErrorKind, TransportResult},
+};
+
+/// A signed transaction of a bundle, decoded from its EIP-2718 encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BundleTransaction {
+    /// The hash of the transaction.
+    pub hash: TxHash,
+    /// The address that signed the transaction.
+    pub sender: Address,
+    /// The nonce of the transaction.
+    pub nonce: u64,
+    /// The chain ID of the transaction, if any.
+    pub chain_id: Option<u64>,
+}
+
+impl BundleTransaction {
+    /// Decodes the given EIP-2718 encoded transaction and recovers its signer.
+    pub fn decode(tx: &[u8]) -> TransportResult<Self> {
+        let envelope = TxEnvelope::decode_2718_exact(tx).map_err(TransportErrorKind::custom)?;
+        let sender = envelope
+            .recover_signer()
+            .map_err(TransportErrorKind::custom)?;
+
+        Ok(Self {
+            hash: *envelope.tx_hash(),
+            sender,
+            nonce: envelope.nonce(),
+            chain_id: envelope.chain_id(),
+        })
+    }
+}
@@ -0,0 +1,38 @@
+use alloy::primitives::{Address, Bytes};
+
+/// Where the tip paying the builder of a bundle is sent, when added using
+/// [`EthBundleBuilder::add_coinbase_tip`].
+///
+/// [`EthBundleBuilder::add_coinbase_tip`]: crate::EthBundleBuilder::add_coinbase_tip
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoinbaseTip {
+    /// The recipient of the tip.
+    pub to: Address,
+    /// The calldata sent along with the tip.
+    pub input: Bytes,
+}
+
+impl CoinbaseTip {
+    /// Creates a new [`CoinbaseTip`] transferring ETH to the given address,
+    /// e.g. the fee recipient of a builder.
+    ///
+    /// The address is paid whichever builder includes the bundle, so it's
+    /// only suited to bundles sent to a single builder. Bundles sent to
+    /// several builders should use [`CoinbaseTip::call`] instead, with a
+    /// contract paying `block.coinbase`.
+    pub const fn transfer(to: Address) -> Self {
+        Self {
+            to,
+            input: Bytes::new(),
+        }
+    }
+
+    /// Creates a new [`CoinbaseTip`] calling the given contract, which is
+    /// expected to forward the value to `block.coinbase`.
+    pub const fn call(contract: Address, input: Bytes) -> Self {
+        Self {
+            to: contract,
+            input,
+        }
+    }
+}
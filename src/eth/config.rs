@@ -0,0 +1,268 @@
+use std::{
+    collections::{HashMap, HashSet},
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use alloy::signers::local::PrivateKeySigner;
+use serde::Deserialize;
+use url::Url;
+
+use super::{Endpoint, Endpoints, KnownBuilder};
+
+/// A list of builders, as found in a TOML or JSON configuration file.
+///
+/// ```toml
+/// [[builders]]
+/// name = "flashbots"
+/// signer = { env = "BUNDLE_SIGNER_KEY" }
+///
+/// [[builders]]
+/// name = "my-builder"
+/// url = "https://rpc.my-builder.xyz"
+/// auth = "flashbots"
+/// signer = { keystore = { path = "./keystore.json", password_env = "KEYSTORE_PASSWORD" } }
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EndpointsConfig {
+    /// The builders requests are broadcast to.
+    #[serde(default)]
+    pub builders: Vec<EndpointConfig>,
+}
+
+/// A builder entry of an [`EndpointsConfig`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct EndpointConfig {
+    /// The name identifying the endpoint. If it matches a [`KnownBuilder`],
+    /// the URL and the authentication mode can be omitted.
+    pub name: String,
+    /// The URL of the RPC.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// How requests are authenticated.
+    #[serde(default)]
+    pub auth: Option<AuthMode>,
+    /// Where to find the signer used for authentication.
+    #[serde(default)]
+    pub signer: Option<SignerConfig>,
+}
+
+/// How requests sent to an endpoint are authenticated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuthMode {
+    /// Requests are not signed.
+    None,
+    /// Requests are signed using the `X-Flashbots-Signature` header.
+    Flashbots,
+}
+
+/// A reference to a signer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignerConfig {
+    /// A hex-encoded private key, read from the given environment variable.
+    Env(String),
+    /// An encrypted keystore file.
+    Keystore {
+        /// The path of the keystore.
+        path: PathBuf,
+        /// The environment variable holding the keystore password.
+        password_env: String,
+    },
+}
+
+impl EndpointsConfig {
+    /// Parses a TOML configuration.
+    pub fn from_toml(s: &str) -> Result<Self, ConfigError> {
+        toml::from_str(s).map_err(|err| ConfigError::Parse(err.to_string()))
+    }
+
+    /// Parses a JSON configuration.
+    pub fn from_json(s: &str) -> Result<Self, ConfigError> {
+        serde_json::from_str(s).map_err(|err| ConfigError::Parse(err.to_string()))
+    }
+
+    /// Reads a configuration file, whose format is picked from its extension.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(ConfigError::Io)?;
+
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => Self::from_toml(&content),
+            Some("json") => Self::from_json(&content),
+            _ => Err(ConfigError::UnsupportedFormat(path.to_path_buf())),
+        }
+    }
+}
+
+impl Endpoints {
+    /// Builds [`Endpoints`] from the given configuration.
+    ///
+    /// All the entries are validated, and if any of them is invalid, the
+    /// returned error lists every bad entry.
+    pub fn from_config(config: &EndpointsConfig) -> Result<Self, ConfigError> {
+        let mut endpoints = Self::default();
+        let mut invalid_entries = Vec::new();
+        let mut signers = HashMap::new();
+        let mut names = HashSet::new();
+
+        for (index, entry) in config.builders.iter().enumerate() {
+            let endpoint = if names.insert(entry.name.as_str()) {
+                entry.to_endpoint(&mut signers)
+            } else {
+                Err(String::from("duplicate name"))
+            };
+
+            match endpoint {
+                Ok(endpoint) => endpoints.add(endpoint),
+                Err(reason) => invalid_entries.push(InvalidEntry {
+                    index,
+                    name: entry.name.clone(),
+                    reason,
+                }),
+            }
+        }
+
+        if invalid_entries.is_empty() {
+            Ok(endpoints)
+        } else {
+            Err(ConfigError::InvalidEntries(invalid_entries))
+        }
+    }
+
+    /// Builds [`Endpoints`] from the given TOML or JSON configuration file.
+    pub fn from_config_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        Self::from_config(&EndpointsConfig::from_file(path)?)
+    }
+}
+
+impl EndpointConfig {
+    fn to_endpoint(
+        &self,
+        signers: &mut HashMap<SignerConfig, PrivateKeySigner>,
+    ) -> Result<Endpoint, String> {
+        let known = KnownBuilder::by_name(&self.name);
+
+        let url = match (&self.url, known) {
+            (Some(url), _) => parse_url(url)?,
+            (None, Some(known)) => parse_url(known.url)?,
+            (None, None) => return Err(String::from("missing URL for an unknown builder")),
+        };
+
+        let auth = self.auth.unwrap_or(match known {
+            Some(known) if known.requires_auth => AuthMode::Flashbots,
+            _ => AuthMode::None,
+        });
+
+        let endpoint = Endpoint::new(url).with_name(&self.name);
+
+        match (auth, &self.signer) {
+            (AuthMode::None, _) => Ok(endpoint),
+            (AuthMode::Flashbots, None) => {
+                Err(String::from("a signer is required for authentication"))
+            }
+            (AuthMode::Flashbots, Some(signer)) => {
+                if !signers.contains_key(signer) {
+                    signers.insert(signer.clone(), signer.load()?);
+                }
+
+                Ok(endpoint.with_signer(signers[signer].clone()))
+            }
+        }
+    }
+}
+
+impl SignerConfig {
+    fn load(&self) -> Result<PrivateKeySigner, String> {
+        match self {
+            Self::Env(var) => env::var(var)
+                .map_err(|err| format!("can't read signer from {var}: {err}"))?
+                .trim()
+                .parse()
+                .map_err(|err| format!("invalid private key in {var}: {err}")),
+            Self::Keystore { path, password_env } => {
+                let password = env::var(password_env)
+                    .map_err(|err| format!("can't read password from {password_env}: {err}"))?;
+
+                PrivateKeySigner::decrypt_keystore(path, password)
+                    .map_err(|err| format!("can't decrypt keystore {}: {err}", path.display()))
+            }
+        }
+    }
+}
+
+fn parse_url(url: &str) -> Result<Url, String> {
+    let url = Url::parse(url).map_err(|err| format!("invalid URL {url}: {err}"))?;
+
+    match url.scheme() {
+        "http" | "https" => Ok(url),
+        scheme => Err(format!("unsupported URL scheme {scheme}")),
+    }
+}
+
+/// An error occurring while loading [`Endpoints`] from a configuration.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The configuration file can't be read.
+    Io(std::io::Error),
+    /// The configuration can't be parsed.
+    Parse(String),
+    /// The configuration file extension is neither `toml` nor `json`.
+    UnsupportedFormat(PathBuf),
+    /// Some entries of the configuration are invalid.
+    InvalidEntries(Vec<InvalidEntry>),
+}
+
+/// An invalid entry of an [`EndpointsConfig`].
+#[derive(Debug, Clone)]
+pub struct InvalidEntry {
+    /// The position of the entry in the configuration.
+    pub index: usize,
+    /// The name of the entry.
+    pub name: String,
+    /// Why the entry is invalid.
+    pub reason: String,
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "can't read configuration: {err}"),
+            Self::Parse(err) => write!(f, "can't parse configuration: {err}"),
+            Self::UnsupportedFormat(path) => {
+                write!(f, "unsupported configuration format: {}", path.display())
+            }
+            Self::InvalidEntries(entries) => {
+                write!(f, "invalid builder entries: ")?;
+
+                for (i, entry) in entries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{entry}")?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl Display for InvalidEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{} ({}): {}", self.index, self.name, self.reason)
+    }
+}
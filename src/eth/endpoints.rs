@@ -1,9 +1,22 @@
-use std::{fmt::Debug, slice::Iter};
+use std::{fmt::Debug, future::IntoFuture, slice::Iter, sync::Arc, time::Instant};
 
-use alloy::signers::Signer;
+use alloy::{
+    providers::ext::MevBuilder,
+    rpc::{
+        client::{RpcCall, RpcClient},
+        json_rpc::{Request, RpcRecv, RpcSend},
+    },
+    signers::Signer,
+    transports::{BoxFuture, IntoBoxTransport, TransportErrorKind, TransportResult},
+};
 use dyn_clone::DynClone;
+use futures::FutureExt;
 use url::Url;
 
+use crate::utils::build_rpc_client;
+
+use super::{KnownBuilder, RetryPolicy};
+
 /// Stores a list of transports that can be used to broadcast a request to.
 #[derive(Default, Debug)]
 pub struct Endpoints(Vec<Endpoint>);
@@ -23,47 +36,301 @@ impl Endpoints {
     pub fn iter(&self) -> Iter<'_, Endpoint> {
         self.0.iter()
     }
+
+    /// Returns `true` if there are no transports.
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the endpoint with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&Endpoint> {
+        self.0.iter().find(|e| e.name == name)
+    }
 }
 
+/// A [`Signer`] that can be cloned and shared between broadcast calls.
 pub trait ClonableSigner: Signer + DynClone + Send + Sync + Debug + 'static {}
 
 impl<T> ClonableSigner for T where T: Signer + Clone + Send + Sync + Debug + 'static {}
 
 dyn_clone::clone_trait_object!(ClonableSigner);
 
+/// An RPC endpoint a request can be broadcast to.
+///
+/// The underlying [`RpcClient`] is created once, along with the endpoint, so
+/// the connections it holds are reused across broadcasts. By default, it uses
+/// an HTTP transport, but any client or transport can be provided using
+/// [`Endpoint::from_client`] or [`Endpoint::from_transport`].
 #[derive(Debug, Clone)]
 pub struct Endpoint {
+    /// The name identifying this endpoint.
+    pub name: String,
+    /// The URL of the RPC.
     pub url: Url,
+    /// The signer used for header authentication, if required.
     pub signer: Option<Box<dyn ClonableSigner>>,
+    /// The policy used to retry requests failing because of transient errors.
+    pub retry_policy: Option<RetryPolicy>,
+    client: RpcClient,
 }
 
+impl Endpoint {
+    /// Creates a new [`Endpoint`], named after the host of its URL.
+    pub fn new(url: Url) -> Self {
+        let client = build_rpc_client(url.clone());
+
+        Self::from_client(url, client)
+    }
+
+    /// Creates a new [`Endpoint`] sending its requests through the given
+    /// client, e.g. a client built with additional tower layers.
+    pub fn from_client(url: Url, client: RpcClient) -> Self {
+        let name = url.host_str().unwrap_or_else(|| url.as_str()).to_string();
+
+        Self {
+            name,
+            url,
+            signer: None,
+            retry_policy: None,
+            client,
+        }
+    }
+
+    /// Creates a new [`Endpoint`] sending its requests through the given
+    /// transport. The URL is only used to identify the endpoint.
+    pub fn from_transport<T>(url: Url, transport: T) -> Self
+    where
+        T: IntoBoxTransport,
+    {
+        Self::from_client(url, RpcClient::new(transport, false))
+    }
+
+    /// Sets the name identifying this endpoint.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+
+        self
+    }
+
+    /// Sets the signer used for header authentication.
+    pub fn with_signer<S: ClonableSigner>(mut self, signer: S) -> Self {
+        self.signer = Some(Box::new(signer));
+
+        self
+    }
+
+    /// Sets the policy used to retry requests failing because of transient
+    /// errors.
+    pub const fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+
+        self
+    }
+
+    /// Returns the client used to send requests to this endpoint.
+    pub const fn client(&self) -> &RpcClient {
+        &self.client
+    }
+
+    /// Sends the given request to this endpoint, retrying it according to
+    /// the retry policy, as long as the given limit is not reached.
+    pub(crate) fn call<Params, Resp>(
+        &self,
+        request: Request<Params>,
+        limit: Option<Instant>,
+    ) -> BoxFuture<'static, TransportResult<Resp>>
+    where
+        Params: RpcSend + 'static,
+        Resp: RpcRecv,
+    {
+        let Some(retry_policy) = self.retry_policy else {
+            return self.send(request);
+        };
+
+        let endpoint = self.clone();
+
+        async move {
+            let mut attempt = 1;
+
+            loop {
+                let result = endpoint.send(request.clone()).await;
+
+                match &result {
+                    Err(err)
+                        if attempt < retry_policy.max_attempts()
+                            && retry_policy.is_retryable(err) =>
+                    {
+                        let backoff = retry_policy.backoff(attempt);
+
+                        let retry_at = Instant::now().checked_add(backoff);
+
+                        if limit.is_some_and(|limit| retry_at.is_none_or(|at| at >= limit)) {
+                            return result;
+                        }
+
+                        tokio::time::sleep(backoff).await;
+                        attempt += 1;
+                    }
+                    _ => return result,
+                }
+            }
+        }
+        .boxed()
+    }
+
+    /// Sends a request to this endpoint that must be signed, returning an
+    /// error if no signer has been set.
+    pub(crate) async fn authenticated_call<Params, Resp>(
+        &self,
+        method: &'static str,
+        params: Params,
+    ) -> TransportResult<Resp>
+    where
+        Params: RpcSend + 'static,
+        Resp: RpcRecv,
+    {
+        if self.signer.is_none() {
+            return Err(TransportErrorKind::custom_str(
+                "the endpoint must have a signer to authenticate the request",
+            ));
+        }
+
+        let request = self.client.make_request(method, params);
+
+        self.call(request, None).await
+    }
+
+    /// Sends the given request to this endpoint, signing it if a signer has
+    /// been set.
+    fn send<Params, Resp>(
+        &self,
+        request: Request<Params>,
+    ) -> BoxFuture<'static, TransportResult<Resp>>
+    where
+        Params: RpcSend + 'static,
+        Resp: RpcRecv,
+    {
+        let rpc_call = RpcCall::new(request, self.client.transport().clone());
+        let mut mev = MevBuilder::new_rpc(rpc_call);
+        if let Some(signer) = &self.signer {
+            mev = mev.with_auth(signer.clone())
+        }
+        mev.into_future().boxed()
+    }
+}
+
+type ClientFactory = Arc<dyn Fn(&Url) -> RpcClient + Send + Sync>;
+
 /// An [`Endpoints`] builder.
-#[derive(Default, Debug)]
+#[derive(Default)]
 pub struct EndpointsBuilder {
     endpoints: Endpoints,
+    client_factory: Option<ClientFactory>,
+    retry_policy: Option<RetryPolicy>,
 }
 
 impl EndpointsBuilder {
-    /// Adds a new transport to the [`Endpoints`] being built.
-    pub fn endpoint(mut self, url: Url) -> Self {
-        self.endpoints.add(Endpoint { url, signer: None });
+    /// Sets the retry policy of the endpoints added afterwards.
+    pub const fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
 
         self
     }
 
-    /// Adds a new transport to the [`Endpoints`] being built, using the given signer for header authentication.
-    pub fn authenticated_endpoint<S: ClonableSigner>(mut self, url: Url, signer: S) -> Self {
-        self.endpoints.add(Endpoint {
-            url,
-            signer: Some(Box::new(signer)),
-        });
+    /// Sets the factory used to create the client of the endpoints added
+    /// afterwards from their URL, instead of the default HTTP client.
+    pub fn client_factory<F>(mut self, factory: F) -> Self
+    where
+        F: Fn(&Url) -> RpcClient + Send + Sync + 'static,
+    {
+        self.client_factory = Some(Arc::new(factory));
 
         self
     }
 
+    /// Adds the given [`Endpoint`] to the [`Endpoints`] being built.
+    pub fn add_endpoint(mut self, endpoint: Endpoint) -> Self {
+        self.endpoints.add(endpoint);
+
+        self
+    }
+
+    /// Adds a new transport to the [`Endpoints`] being built.
+    pub fn endpoint(self, url: Url) -> Self {
+        let endpoint = self.new_endpoint(url);
+
+        self.add_endpoint(endpoint)
+    }
+
+    /// Adds a new transport to the [`Endpoints`] being built, using the given signer for header authentication.
+    pub fn authenticated_endpoint<S: ClonableSigner>(self, url: Url, signer: S) -> Self {
+        let endpoint = self.new_endpoint(url).with_signer(signer);
+
+        self.add_endpoint(endpoint)
+    }
+
+    /// Adds a new transport identified by the given name to the [`Endpoints`] being built.
+    pub fn named_endpoint(self, name: impl Into<String>, url: Url) -> Self {
+        let endpoint = self.new_endpoint(url).with_name(name);
+
+        self.add_endpoint(endpoint)
+    }
+
+    /// Adds a new transport identified by the given name to the [`Endpoints`] being built,
+    /// using the given signer for header authentication.
+    pub fn named_authenticated_endpoint<S: ClonableSigner>(
+        self,
+        name: impl Into<String>,
+        url: Url,
+        signer: S,
+    ) -> Self {
+        let endpoint = self.new_endpoint(url).with_name(name).with_signer(signer);
+
+        self.add_endpoint(endpoint)
+    }
+
+    /// Adds the given [`KnownBuilder`], that doesn't require authentication.
+    ///
+    /// **Note**: [`KnownBuilder::by_name`] can be used to select a builder from
+    /// its name.
+    ///
+    /// Returns an error if the builder requires authentication, in which
+    /// case [`authenticated_known_builder`] must be used instead.
+    ///
+    /// [`authenticated_known_builder`]: EndpointsBuilder::authenticated_known_builder
+    pub fn known_builder(self, builder: &KnownBuilder) -> TransportResult<Self> {
+        if builder.requires_auth {
+            return Err(TransportErrorKind::custom_str(&format!(
+                "{} requires authentication, use `authenticated_known_builder` instead",
+                builder.name
+            )));
+        }
+
+        Ok(self.unauthenticated_known_builder(builder))
+    }
+
+    /// Adds the given [`KnownBuilder`]. The signer is only used if the builder
+    /// requires authentication.
+    ///
+    /// **Note**: [`KnownBuilder::by_name`] can be used to select a builder from
+    /// its name.
+    pub fn authenticated_known_builder<S: ClonableSigner>(
+        self,
+        builder: &KnownBuilder,
+        bundle_signer: S,
+    ) -> Self {
+        let url = builder.url.parse().unwrap();
+
+        if builder.requires_auth {
+            self.named_authenticated_endpoint(builder.name, url, bundle_signer)
+        } else {
+            self.named_endpoint(builder.name, url)
+        }
+    }
+
     /// Adds Beaverbuild.
     pub fn beaverbuild(self) -> Self {
-        self.endpoint("https://rpc.beaverbuild.org".parse().unwrap())
+        self.unauthenticated_known_builder(&KnownBuilder::BEAVERBUILD)
     }
 
     /// Adds Titan using AWS geo-routing to find the best RPC to send to.
@@ -76,51 +343,63 @@ impl EndpointsBuilder {
     /// [`titan_united_states`]: EndpointsBuilder::titan_united_states
     /// [`titan_asia`]: EndpointsBuilder::titan_asia
     pub fn titan<S: ClonableSigner>(self, bundle_signer: S) -> Self {
-        self.authenticated_endpoint(
-            "https://rpc.titanbuilder.xyz".parse().unwrap(),
-            bundle_signer,
-        )
+        self.authenticated_known_builder(&KnownBuilder::TITAN, bundle_signer)
     }
 
     /// Adds Titan, using the Europe RPC.
     pub fn titan_europe<S: ClonableSigner>(self, bundle_signer: S) -> Self {
-        self.authenticated_endpoint(
-            "https://eu.rpc.titanbuilder.xyz".parse().unwrap(),
-            bundle_signer,
-        )
+        self.authenticated_known_builder(&KnownBuilder::TITAN_EUROPE, bundle_signer)
     }
 
     /// Adds Titan, using the United States RPC.
     pub fn titan_united_states<S: ClonableSigner>(self, bundle_signer: S) -> Self {
-        self.authenticated_endpoint(
-            "https://us.rpc.titanbuilder.xyz".parse().unwrap(),
-            bundle_signer,
-        )
+        self.authenticated_known_builder(&KnownBuilder::TITAN_UNITED_STATES, bundle_signer)
     }
 
     /// Adds Titan, using the Asia RPC.
     pub fn titan_asia<S: ClonableSigner>(self, bundle_signer: S) -> Self {
-        self.authenticated_endpoint(
-            "https://as.rpc.titanbuilder.xyz".parse().unwrap(),
-            bundle_signer,
-        )
+        self.authenticated_known_builder(&KnownBuilder::TITAN_ASIA, bundle_signer)
     }
 
     /// Adds Rsync.
     pub fn rsync(self) -> Self {
-        self.endpoint("https://rsync-builder.xyz".parse().unwrap())
+        self.unauthenticated_known_builder(&KnownBuilder::RSYNC)
     }
 
     /// Adds Flashbots.
     pub fn flashbots<S: ClonableSigner>(self, bundle_signer: S) -> Self {
-        self.authenticated_endpoint(
-            "https://relay.flashbots.net".parse().unwrap(),
-            bundle_signer,
-        )
+        self.authenticated_known_builder(&KnownBuilder::FLASHBOTS, bundle_signer)
     }
 
     /// Returns the [`Endpoints`] struct.
     pub fn build(self) -> Endpoints {
         self.endpoints
     }
+
+    fn unauthenticated_known_builder(self, builder: &KnownBuilder) -> Self {
+        self.named_endpoint(builder.name, builder.url.parse().unwrap())
+    }
+
+    fn new_endpoint(&self, url: Url) -> Endpoint {
+        let mut endpoint = match &self.client_factory {
+            Some(factory) => {
+                let client = factory(&url);
+                Endpoint::from_client(url, client)
+            }
+            None => Endpoint::new(url),
+        };
+
+        endpoint.retry_policy = self.retry_policy;
+
+        endpoint
+    }
+}
+
+impl Debug for EndpointsBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EndpointsBuilder")
+            .field("endpoints", &self.endpoints)
+            .field("retry_policy", &self.retry_policy)
+            .finish()
+    }
 }
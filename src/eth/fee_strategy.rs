@@ -0,0 +1,91 @@
+use alloy::{
+    consensus::BlockHeader,
+    eips::{
+        eip1559::{calc_next_block_base_fee, BaseFeeParams},
+        BlockNumberOrTag,
+    },
+    network::Network,
+    providers::Provider,
+    transports::{TransportErrorKind, TransportResult},
+};
+
+/// The number of recent blocks whose priority fees are looked at by
+/// [`FeeStrategy::Percentile`].
+const FEE_HISTORY_BLOCKS: u64 = 10;
+
+/// How the fees of the transaction requests added to an
+/// [`EthBundleBuilder`] are priced, set using
+/// [`EthBundleBuilder::with_fee_strategy`].
+///
+/// The max fee per gas is always the predicted base fee of the last target
+/// block, plus the priority fee picked by the strategy.
+///
+/// [`EthBundleBuilder`]: crate::EthBundleBuilder
+/// [`EthBundleBuilder::with_fee_strategy`]: crate::EthBundleBuilder::with_fee_strategy
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FeeStrategy {
+    /// Pays a fixed priority fee per gas, e.g. when the builder is paid by a
+    /// coinbase transfer instead.
+    Fixed(u128),
+    /// Pays the average of the given percentile, between 0 and 100, of the
+    /// priority fees paid in the recent blocks. Adding a transaction request
+    /// fails if the percentile is out of this range.
+    Percentile(f64),
+}
+
+impl FeeStrategy {
+    /// Predicts the base fee of the given block from its parent.
+    ///
+    /// The base fee of the block right after the parent is known for sure.
+    /// For the next ones, the blocks in between are assumed to be full, so
+    /// the prediction is the highest possible base fee. Returns `None` if the
+    /// parent has no base fee, or if the block isn't after the parent.
+    pub fn predict_base_fee<H: BlockHeader>(parent: &H, block_number: u64) -> Option<u64> {
+        let params = BaseFeeParams::ethereum();
+        let blocks_ahead = block_number.checked_sub(parent.number())?;
+        let mut base_fee = parent.next_block_base_fee(params)?;
+
+        for _ in 1..blocks_ahead {
+            base_fee =
+                calc_next_block_base_fee(parent.gas_limit(), parent.gas_limit(), base_fee, params);
+        }
+
+        (blocks_ahead > 0).then_some(base_fee)
+    }
+
+    /// Returns the priority fee per gas to pay.
+    pub(crate) async fn priority_fee<P, N>(&self, provider: &P) -> TransportResult<u128>
+    where
+        P: Provider<N>,
+        N: Network,
+    {
+        match *self {
+            Self::Fixed(priority_fee) => Ok(priority_fee),
+            Self::Percentile(percentile) => {
+                if !(0.0..=100.0).contains(&percentile) {
+                    return Err(TransportErrorKind::custom_str(&format!(
+                        "the percentile {percentile} isn't between 0 and 100"
+                    )));
+                }
+
+                let history = provider
+                    .get_fee_history(FEE_HISTORY_BLOCKS, BlockNumberOrTag::Latest, &[percentile])
+                    .await?;
+                let rewards: Vec<u128> = history
+                    .reward
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|rewards| rewards.first().copied())
+                    .collect();
+
+                if rewards.is_empty() {
+                    return Err(TransportErrorKind::custom_str(
+                        "the fee history has no priority fees",
+                    ));
+                }
+
+                Ok(rewards.iter().sum::<u128>() / rewards.len() as u128)
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+/// A block builder known by this crate, that can be selected by name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnownBuilder {
+    /// The stable name identifying the builder.
+    pub name: &'static str,
+    /// The URL of the builder RPC.
+    pub url: &'static str,
+    /// Whether the builder requires the requests to be signed.
+    pub requires_auth: bool,
+    /// The RPC methods supported by the builder.
+    pub methods: &'static [&'static str],
+}
+
+const BUNDLE_METHODS: &[&str] = &[
+    "eth_sendBundle",
+    "eth_cancelBundle",
+    "eth_sendPrivateTransaction",
+    "eth_sendPrivateRawTransaction",
+];
+
+const FLASHBOTS_METHODS: &[&str] = &[
+    "eth_sendBundle",
+    "eth_callBundle",
+    "eth_cancelBundle",
+    "eth_sendPrivateTransaction",
+    "eth_sendPrivateRawTransaction",
+    "eth_cancelPrivateTransaction",
+    "mev_sendBundle",
+    "mev_simBundle",
+    "flashbots_getBundleStatsV2",
+    "flashbots_getUserStatsV2",
+];
+
+const KNOWN_BUILDERS: &[KnownBuilder] = &[
+    KnownBuilder::FLASHBOTS,
+    KnownBuilder::BEAVERBUILD,
+    KnownBuilder::TITAN,
+    KnownBuilder::TITAN_EUROPE,
+    KnownBuilder::TITAN_UNITED_STATES,
+    KnownBuilder::TITAN_ASIA,
+    KnownBuilder::RSYNC,
+    KnownBuilder::BUILDERNET,
+    KnownBuilder::BLOXROUTE,
+    KnownBuilder::QUASAR,
+];
+
+impl KnownBuilder {
+    /// Flashbots.
+    pub const FLASHBOTS: Self = Self {
+        name: "flashbots",
+        url: "https://relay.flashbots.net",
+        requires_auth: true,
+        methods: FLASHBOTS_METHODS,
+    };
+
+    /// Beaverbuild.
+    pub const BEAVERBUILD: Self = Self {
+        name: "beaverbuild",
+        url: "https://rpc.beaverbuild.org",
+        requires_auth: false,
+        methods: BUNDLE_METHODS,
+    };
+
+    /// Titan, using AWS geo-routing to find the best RPC to send to.
+    pub const TITAN: Self = Self {
+        name: "titan",
+        url: "https://rpc.titanbuilder.xyz",
+        requires_auth: true,
+        methods: BUNDLE_METHODS,
+    };
+
+    /// Titan, using the Europe RPC.
+    pub const TITAN_EUROPE: Self = Self {
+        name: "titan-europe",
+        url: "https://eu.rpc.titanbuilder.xyz",
+        requires_auth: true,
+        methods: BUNDLE_METHODS,
+    };
+
+    /// Titan, using the United States RPC.
+    pub const TITAN_UNITED_STATES: Self = Self {
+        name: "titan-united-states",
+        url: "https://us.rpc.titanbuilder.xyz",
+        requires_auth: true,
+        methods: BUNDLE_METHODS,
+    };
+
+    /// Titan, using the Asia RPC.
+    pub const TITAN_ASIA: Self = Self {
+        name: "titan-asia",
+        url: "https://as.rpc.titanbuilder.xyz",
+        requires_auth: true,
+        methods: BUNDLE_METHODS,
+    };
+
+    /// Rsync.
+    pub const RSYNC: Self = Self {
+        name: "rsync",
+        url: "https://rsync-builder.xyz",
+        requires_auth: false,
+        methods: BUNDLE_METHODS,
+    };
+
+    /// BuilderNet.
+    pub const BUILDERNET: Self = Self {
+        name: "buildernet",
+        url: "https://rpc.buildernet.org",
+        requires_auth: true,
+        methods: BUNDLE_METHODS,
+    };
+
+    /// bloXroute.
+    pub const BLOXROUTE: Self = Self {
+        name: "bloxroute",
+        url: "https://rpc-builder.blxrbdn.com",
+        requires_auth: false,
+        methods: BUNDLE_METHODS,
+    };
+
+    /// Quasar.
+    pub const QUASAR: Self = Self {
+        name: "quasar",
+        url: "https://rpc.quasar.win",
+        requires_auth: false,
+        methods: BUNDLE_METHODS,
+    };
+
+    /// Returns all the builders known by this crate.
+    pub const fn all() -> &'static [KnownBuilder] {
+        KNOWN_BUILDERS
+    }
+
+    /// Returns the builder with the given name, if known.
+    pub fn by_name(name: &str) -> Option<&'static KnownBuilder> {
+        KNOWN_BUILDERS.iter().find(|b| b.name == name)
+    }
+
+    /// Returns `true` if the builder supports the given RPC method.
+    pub fn supports(&self, method: &str) -> bool {
+        self.methods.contains(&method)
+    }
+}
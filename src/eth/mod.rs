@@ -1,11 +1,67 @@
+mod broadcast_response;
+pub use broadcast_response::BroadcastResponse;
+
 mod broadcastable_call;
 pub use broadcastable_call::BroadcastableCall;
 
 mod bundle_builder;
 pub use bundle_builder::EthBundleBuilder;
 
+mod bundle_handle;
+pub use bundle_handle::{BundleAcknowledgement, BundleHandle, BundleOperation};
+
+#[cfg(feature = "revm")]
+mod bundle_simulator;
+#[cfg(feature = "revm")]
+pub use bundle_simulator::BundleSimulator;
+
+mod bundle_submitter;
+pub use bundle_submitter::{BundleSubmitter, SubmissionOutcome};
+
+mod bundle_tracker;
+pub use bundle_tracker::{BundleTracker, IncludingBlock, InclusionStatus};
+
+mod bundle_transaction;
+pub use bundle_transaction::BundleTransaction;
+
+mod coinbase_tip;
+pub use coinbase_tip::CoinbaseTip;
+
+#[cfg(feature = "serde")]
+mod config;
+#[cfg(feature = "serde")]
+pub use config::{
+    AuthMode, ConfigError, EndpointConfig, EndpointsConfig, InvalidEntry, SignerConfig,
+};
+
 mod endpoints;
-pub use endpoints::{Endpoints, EndpointsBuilder};
+pub use endpoints::{ClonableSigner, Endpoint, Endpoints, EndpointsBuilder};
+
+mod fee_strategy;
+pub use fee_strategy::FeeStrategy;
+
+mod known_builder;
+pub use known_builder::KnownBuilder;
+
+mod nonce_tracker;
+pub use nonce_tracker::NonceTracker;
+
+mod private_transaction_builder;
+pub use private_transaction_builder::EthPrivateTransactionBuilder;
 
 mod provider_ext;
 pub use provider_ext::EthMevProviderExt;
+
+mod retry_policy;
+pub use retry_policy::RetryPolicy;
+
+mod simulation_criteria;
+pub use simulation_criteria::{SimulationCriteria, SimulationError};
+
+mod simulation_report;
+pub use simulation_report::{
+    EndpointSimulation, RevertReason, SimulationReport, TransactionAnalysis,
+};
+
+mod transaction_flags;
+pub use transaction_flags::TransactionFlags;
@@ -0,0 +1,72 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use alloy::primitives::Address;
+
+/// Tracks the next nonce of each sender, so the transactions of a bundle
+/// sent by the same account get sequential nonces.
+///
+/// Each [`EthBundleBuilder`] has its own tracker by default. A tracker can be
+/// shared between builders using [`EthBundleBuilder::with_nonce_tracker`], so
+/// the bundles built concurrently don't reuse the same nonces.
+///
+/// [`EthBundleBuilder`]: crate::EthBundleBuilder
+/// [`EthBundleBuilder::with_nonce_tracker`]: crate::EthBundleBuilder::with_nonce_tracker
+#[derive(Debug, Clone, Default)]
+pub struct NonceTracker(Arc<Mutex<HashMap<Address, u64>>>);
+
+impl NonceTracker {
+    /// Creates a new, empty, [`NonceTracker`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the next nonce of the given sender, if any of its transactions
+    /// has been seen.
+    pub fn next_nonce(&self, sender: Address) -> Option<u64> {
+        self.nonces().get(&sender).copied()
+    }
+
+    /// Returns the next nonce of the given sender, if any of its transactions
+    /// has been seen, and then increments it.
+    pub fn reserve(&self, sender: Address) -> Option<u64> {
+        self.nonces().get_mut(&sender).map(|next| {
+            let nonce = *next;
+            *next += 1;
+            nonce
+        })
+    }
+
+    /// Gives back a nonce of the given sender returned by [`reserve`], e.g.
+    /// when its transaction can't be signed, unless a later nonce has been
+    /// reserved since.
+    ///
+    /// [`reserve`]: NonceTracker::reserve
+    pub fn release(&self, sender: Address, nonce: u64) {
+        if let Some(next) = self.nonces().get_mut(&sender) {
+            if *next == nonce + 1 {
+                *next = nonce;
+            }
+        }
+    }
+
+    /// Records a transaction of the given sender using the given nonce.
+    pub fn record(&self, sender: Address, nonce: u64) {
+        let mut nonces = self.nonces();
+        let next = nonces.entry(sender).or_default();
+
+        *next = (*next).max(nonce + 1);
+    }
+
+    /// Forgets the nonce of the given sender, so it's fetched again from the
+    /// provider for its next transaction.
+    pub fn reset(&self, sender: Address) {
+        self.nonces().remove(&sender);
+    }
+
+    fn nonces(&self) -> MutexGuard<'_, HashMap<Address, u64>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
@@ -0,0 +1,80 @@
+use std::marker::PhantomData;
+
+use alloy::{
+    network::Network,
+    primitives::{Bytes, B256},
+    providers::Provider,
+    rpc::types::mev::{EthSendPrivateTransaction, PrivateTransactionPreferences},
+};
+
+use super::{BroadcastableCall, Endpoints, EthMevProviderExt};
+
+/// A private transaction that can be sent to one or more builder(s).
+#[derive(Debug)]
+pub struct EthPrivateTransactionBuilder<'a, P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    provider: &'a P,
+    request: EthSendPrivateTransaction,
+    phantom: PhantomData<N>,
+}
+
+impl<'a, P, N> EthPrivateTransactionBuilder<'a, P, N>
+where
+    P: Provider<N>,
+    N: Network,
+{
+    /// Creates a new [`EthPrivateTransactionBuilder`] for the given
+    /// hex-encoded signed transaction.
+    pub fn new(provider: &'a P, tx: Bytes) -> Self {
+        Self {
+            provider,
+            request: EthSendPrivateTransaction {
+                tx,
+                max_block_number: None,
+                preferences: PrivateTransactionPreferences::default(),
+            },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Sets the highest block number the transaction can be included in.
+    pub const fn with_max_block_number(mut self, max_block_number: u64) -> Self {
+        self.request.max_block_number = Some(max_block_number);
+
+        self
+    }
+
+    /// Sets whether the transaction is shared with all the builders the
+    /// endpoints know of, to be included faster.
+    pub const fn with_fast_mode(mut self, fast: bool) -> Self {
+        self.request.preferences.fast = Some(fast);
+
+        self
+    }
+
+    /// Sets the preferences of the transaction, replacing the fast mode if
+    /// already set.
+    pub fn with_preferences(mut self, preferences: PrivateTransactionPreferences) -> Self {
+        self.request.preferences = preferences;
+
+        self
+    }
+
+    /// Builds a [`EthSendPrivateTransaction`].
+    pub fn build(self) -> EthSendPrivateTransaction {
+        self.request
+    }
+
+    /// Sends the transaction to the given endpoints using
+    /// [`EthMevProviderExt::send_eth_private_transaction_to`].
+    pub fn send(
+        self,
+        endpoints: &Endpoints,
+    ) -> BroadcastableCall<(EthSendPrivateTransaction,), B256> {
+        self.provider
+            .send_eth_private_transaction_to(self.request, endpoints)
+    }
+}
@@ -1,18 +1,26 @@
+use std::collections::BTreeMap;
+
 use alloy::{
     network::Network,
-    primitives::B256,
+    primitives::{Bytes, B256},
     providers::Provider,
     rpc::types::mev::{
-        EthBundleHash, EthCallBundle, EthCallBundleResponse, EthCancelBundle, EthSendBundle,
-        EthSendPrivateTransaction,
+        BundleStats, EthBundleHash, EthCallBundle, EthCallBundleResponse, EthCancelBundle,
+        EthCancelPrivateTransaction, EthSendBundle, EthSendPrivateTransaction, UserStats,
     },
     transports::TransportResult,
 };
 use async_trait::async_trait;
+use futures::{future::join_all, FutureExt};
 
-use crate::{eth::EthBundleBuilder, BroadcastableCall};
+use crate::{
+    eth::{BundleSubmitter, BundleTracker, EthBundleBuilder, EthPrivateTransactionBuilder},
+    BroadcastResponse, BroadcastableCall,
+};
 
-use super::{Endpoints, EndpointsBuilder};
+#[cfg(feature = "revm")]
+use super::BundleSimulator;
+use super::{Endpoint, Endpoints, EndpointsBuilder};
 
 /// Extension trait for sending and simulate eth bundles.
 #[async_trait]
@@ -28,12 +36,48 @@ where
     fn bundle_builder(&self) -> EthBundleBuilder<'_, Self, N>;
 
     /// Submits a bundle to one or more builder(s). It takes in a bundle and
-    /// provides a bundle hash as a return value.
-    async fn send_eth_bundle(
+    /// provides, for each builder, a [`BroadcastResponse`] holding the bundle
+    /// hash.
+    ///
+    /// The returned [`BroadcastableCall`] can be configured with a timeout
+    /// or a deadline before being awaited.
+    ///
+    /// [`BroadcastResponse`]: crate::BroadcastResponse
+    fn send_eth_bundle(
         &self,
         bundle: EthSendBundle,
         endpoints: &Endpoints,
-    ) -> Vec<TransportResult<EthBundleHash>>;
+    ) -> BroadcastableCall<(EthSendBundle,), EthBundleHash>;
+
+    /// Returns a [`BundleSubmitter`] resending the given bundle to one or
+    /// more builder(s) on each new block, until it lands or expires.
+    fn bundle_submitter<'a>(
+        &'a self,
+        bundle: EthSendBundle,
+        endpoints: &'a Endpoints,
+    ) -> BundleSubmitter<'a, Self, N>;
+
+    /// Returns a [`BundleTracker`] telling whether the given bundle has
+    /// landed on chain.
+    fn track_bundle(&self, bundle: &EthSendBundle) -> TransportResult<BundleTracker<'_, Self, N>>;
+
+    /// Returns a [`BundleSimulator`] executing the given bundle locally,
+    /// without depending on a builder implementing `eth_callBundle`.
+    #[cfg(feature = "revm")]
+    fn bundle_simulator(
+        &self,
+        bundle: &EthSendBundle,
+    ) -> TransportResult<BundleSimulator<'_, Self, N>>;
+
+    /// Submits bundles targeting different blocks to one or more builder(s),
+    /// e.g. the ones built using [`EthBundleBuilder::build_for_blocks`]. The
+    /// responses are grouped by block, in the order the bundles were given,
+    /// so that bundles targeting the same block are all kept.
+    async fn send_eth_bundles(
+        &self,
+        bundles: Vec<EthSendBundle>,
+        endpoints: &Endpoints,
+    ) -> BTreeMap<u64, Vec<Vec<BroadcastResponse<EthBundleHash>>>>;
 
     /// Submits a single transaction to one or more builder(s). It takes in a
     /// bundle and provides a bundle hash as a return value.
@@ -42,15 +86,73 @@ where
         request: EthSendPrivateTransaction,
     ) -> TransportResult<B256>;
 
-    /// simulates a bundle against a specific block number.
-    async fn call_eth_bundle(
+    /// Returns a builder-style [`EthPrivateTransactionBuilder`] for the given
+    /// hex-encoded signed transaction.
+    fn private_transaction_builder(&self, tx: Bytes) -> EthPrivateTransactionBuilder<'_, Self, N>;
+
+    /// Submits a single transaction to one or more builder(s). It provides,
+    /// for each builder, a [`BroadcastResponse`] holding the transaction
+    /// hash.
+    ///
+    /// [`BroadcastResponse`]: crate::BroadcastResponse
+    fn send_eth_private_transaction_to(
+        &self,
+        request: EthSendPrivateTransaction,
+        endpoints: &Endpoints,
+    ) -> BroadcastableCall<(EthSendPrivateTransaction,), B256>;
+
+    /// Cancels a private transaction previously submitted to one or more
+    /// builder(s). It provides, for each builder, a [`BroadcastResponse`]
+    /// telling whether the transaction has been cancelled.
+    ///
+    /// [`BroadcastResponse`]: crate::BroadcastResponse
+    fn cancel_eth_private_transaction_to(
+        &self,
+        request: EthCancelPrivateTransaction,
+        endpoints: &Endpoints,
+    ) -> BroadcastableCall<(EthCancelPrivateTransaction,), bool>;
+
+    /// simulates a bundle against a specific block number. It provides a
+    /// [`BroadcastResponse`] for each builder.
+    ///
+    /// [`BroadcastResponse`]: crate::BroadcastResponse
+    fn call_eth_bundle(
         &self,
         bundle: EthCallBundle,
         endpoints: &Endpoints,
-    ) -> Vec<TransportResult<EthCallBundleResponse>>;
+    ) -> BroadcastableCall<(EthCallBundle,), EthCallBundleResponse>;
 
     /// Cancels a previously submitted bundle.
     async fn cancel_eth_bundle(&self, request: EthCancelBundle) -> TransportResult<()>;
+
+    /// Cancels a bundle previously submitted to one or more builder(s),
+    /// using its replacement UUID. It provides a [`BroadcastResponse`] for
+    /// each builder.
+    ///
+    /// [`BroadcastResponse`]: crate::BroadcastResponse
+    fn cancel_eth_bundle_to(
+        &self,
+        request: EthCancelBundle,
+        endpoints: &Endpoints,
+    ) -> BroadcastableCall<(EthCancelBundle,), ()>;
+
+    /// Returns the stats of a bundle submitted to Flashbots for the given
+    /// block, using `flashbots_getBundleStatsV2`. The request is signed with
+    /// the endpoint's signer, that must be the one the bundle was sent with.
+    async fn get_bundle_stats(
+        &self,
+        bundle_hash: B256,
+        block_number: u64,
+        endpoint: &Endpoint,
+    ) -> TransportResult<BundleStats>;
+
+    /// Returns the stats of the searcher identified by the endpoint's signer,
+    /// using `flashbots_getUserStatsV2`.
+    async fn get_user_stats(
+        &self,
+        block_number: u64,
+        endpoint: &Endpoint,
+    ) -> TransportResult<UserStats>;
 }
 
 #[async_trait]
@@ -67,16 +169,57 @@ where
         EthBundleBuilder::new(self)
     }
 
-    async fn send_eth_bundle(
+    fn send_eth_bundle(
         &self,
         bundle: EthSendBundle,
         endpoints: &Endpoints,
-    ) -> Vec<TransportResult<EthBundleHash>> {
+    ) -> BroadcastableCall<(EthSendBundle,), EthBundleHash> {
         BroadcastableCall::new(
             endpoints,
             self.client().make_request("eth_sendBundle", (bundle,)),
         )
-        .await
+    }
+
+    fn bundle_submitter<'a>(
+        &'a self,
+        bundle: EthSendBundle,
+        endpoints: &'a Endpoints,
+    ) -> BundleSubmitter<'a, Self, N> {
+        BundleSubmitter::new(self, bundle, endpoints)
+    }
+
+    fn track_bundle(&self, bundle: &EthSendBundle) -> TransportResult<BundleTracker<'_, Self, N>> {
+        BundleTracker::new(self, bundle)
+    }
+
+    #[cfg(feature = "revm")]
+    fn bundle_simulator(
+        &self,
+        bundle: &EthSendBundle,
+    ) -> TransportResult<BundleSimulator<'_, Self, N>> {
+        BundleSimulator::new(self, bundle)
+    }
+
+    async fn send_eth_bundles(
+        &self,
+        bundles: Vec<EthSendBundle>,
+        endpoints: &Endpoints,
+    ) -> BTreeMap<u64, Vec<Vec<BroadcastResponse<EthBundleHash>>>> {
+        let calls = bundles.into_iter().map(|bundle| {
+            let block_number = bundle.block_number;
+            self.send_eth_bundle(bundle, endpoints)
+                .map(move |responses| (block_number, responses))
+        });
+
+        let mut responses = BTreeMap::<_, Vec<_>>::new();
+        for (block_number, bundle_responses) in join_all(calls).await {
+            responses
+                .entry(block_number)
+                .or_default()
+                .push(bundle_responses);
+        }
+
+        responses
     }
 
     async fn send_eth_private_transaction(
@@ -88,19 +231,85 @@ where
             .await
     }
 
-    async fn call_eth_bundle(
+    fn private_transaction_builder(&self, tx: Bytes) -> EthPrivateTransactionBuilder<'_, Self, N> {
+        EthPrivateTransactionBuilder::new(self, tx)
+    }
+
+    fn send_eth_private_transaction_to(
+        &self,
+        request: EthSendPrivateTransaction,
+        endpoints: &Endpoints,
+    ) -> BroadcastableCall<(EthSendPrivateTransaction,), B256> {
+        BroadcastableCall::new(
+            endpoints,
+            self.client()
+                .make_request("eth_sendPrivateTransaction", (request,)),
+        )
+    }
+
+    fn cancel_eth_private_transaction_to(
+        &self,
+        request: EthCancelPrivateTransaction,
+        endpoints: &Endpoints,
+    ) -> BroadcastableCall<(EthCancelPrivateTransaction,), bool> {
+        BroadcastableCall::new(
+            endpoints,
+            self.client()
+                .make_request("eth_cancelPrivateTransaction", (request,)),
+        )
+    }
+
+    fn call_eth_bundle(
         &self,
         bundle: EthCallBundle,
         endpoints: &Endpoints,
-    ) -> Vec<TransportResult<EthCallBundleResponse>> {
+    ) -> BroadcastableCall<(EthCallBundle,), EthCallBundleResponse> {
         BroadcastableCall::new(
             endpoints,
             self.client().make_request("eth_callBundle", (bundle,)),
         )
-        .await
     }
 
     async fn cancel_eth_bundle(&self, request: EthCancelBundle) -> TransportResult<()> {
         self.client().request("eth_cancelBundle", (request,)).await
     }
+
+    fn cancel_eth_bundle_to(
+        &self,
+        request: EthCancelBundle,
+        endpoints: &Endpoints,
+    ) -> BroadcastableCall<(EthCancelBundle,), ()> {
+        BroadcastableCall::new(
+            endpoints,
+            self.client().make_request("eth_cancelBundle", (request,)),
+        )
+    }
+
+    async fn get_bundle_stats(
+        &self,
+        bundle_hash: B256,
+        block_number: u64,
+        endpoint: &Endpoint,
+    ) -> TransportResult<BundleStats> {
+        let params = BTreeMap::from([
+            ("bundleHash", bundle_hash.to_string()),
+            ("blockNumber", format!("{block_number:#x}")),
+        ]);
+
+        endpoint
+            .authenticated_call("flashbots_getBundleStatsV2", (params,))
+            .await
+    }
+
+    async fn get_user_stats(
+        &self,
+        block_number: u64,
+        endpoint: &Endpoint,
+    ) -> TransportResult<UserStats> {
+        let params = BTreeMap::from([("blockNumber", format!("{block_number:#x}"))]);
+
+        endpoint
+            .authenticated_call("flashbots_getUserStatsV2", (params,))
+            .await
+    }
 }
@@ -0,0 +1,147 @@
+use std::{
+    error::Error,
+    io::{self, ErrorKind},
+    time::Duration,
+};
+
+use alloy::transports::{RpcError, TransportError, TransportErrorKind};
+
+/// Describes how requests failing because of transient errors are retried.
+///
+/// Retries are never attempted past the timeout or the deadline of a
+/// [`BroadcastableCall`]. Calls have neither by default, e.g. the ones
+/// returned by [`EthMevProviderExt::send_eth_bundle`], so retries are then
+/// only bounded by the maximum number of attempts and the backoff. Set a
+/// deadline, e.g. the timestamp of the target block, to never retry past it.
+///
+/// [`EthMevProviderExt::send_eth_bundle`]: crate::EthMevProviderExt::send_eth_bundle
+///
+/// [`BroadcastableCall`]: crate::BroadcastableCall
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    retry_rate_limited: bool,
+    retry_server_errors: bool,
+    retry_connection_errors: bool,
+}
+
+impl RetryPolicy {
+    /// Creates a new [`RetryPolicy`] sending a request at most `max_attempts`
+    /// times. By default, rate limited requests, server errors and connection
+    /// errors are retried, with an exponential backoff starting at 100ms and
+    /// capped to 1s.
+    pub const fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+            retry_rate_limited: true,
+            retry_server_errors: true,
+            retry_connection_errors: true,
+        }
+    }
+
+    /// Sets the delay before the first retry, doubled after each attempt
+    /// until `max_backoff` is reached.
+    pub const fn with_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+
+        self
+    }
+
+    /// Sets whether requests rejected with an HTTP 429 status are retried.
+    pub const fn retry_rate_limited(mut self, retry: bool) -> Self {
+        self.retry_rate_limited = retry;
+
+        self
+    }
+
+    /// Sets whether requests rejected with an HTTP 5xx status are retried.
+    pub const fn retry_server_errors(mut self, retry: bool) -> Self {
+        self.retry_server_errors = retry;
+
+        self
+    }
+
+    /// Sets whether requests failing because of a connection error are
+    /// retried, e.g. when the connection is refused, reset or times out.
+    pub const fn retry_connection_errors(mut self, retry: bool) -> Self {
+        self.retry_connection_errors = retry;
+
+        self
+    }
+
+    /// Returns the maximum number of times a request is sent.
+    pub const fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns `true` if the given error is retryable according to this policy.
+    pub fn is_retryable(&self, err: &TransportError) -> bool {
+        match err {
+            RpcError::Transport(TransportErrorKind::HttpError(err)) => {
+                (self.retry_rate_limited && err.status == 429)
+                    || (self.retry_server_errors && (500..600).contains(&err.status))
+            }
+            RpcError::Transport(TransportErrorKind::Custom(err)) => {
+                self.retry_connection_errors && is_connection_error(&**err)
+            }
+            RpcError::Transport(TransportErrorKind::BackendGone) => self.retry_connection_errors,
+            RpcError::ErrorResp(payload) => self.retry_rate_limited && payload.code == 429,
+            _ => false,
+        }
+    }
+
+    /// Returns the delay to wait before sending the request again, after the
+    /// given attempt failed.
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_backoff)
+    }
+}
+
+/// Returns `true` if the given error, or one of its sources, is a failure to
+/// connect to the endpoint, or a dropped or timed out connection.
+fn is_connection_error(err: &(dyn Error + 'static)) -> bool {
+    let mut source = Some(err);
+
+    while let Some(err) = source {
+        #[cfg(feature = "reqwest")]
+        if let Some(err) = err.downcast_ref::<alloy::transports::http::reqwest::Error>() {
+            if err.is_connect() || err.is_timeout() {
+                return true;
+            }
+        }
+
+        #[cfg(feature = "hyper")]
+        if let Some(err) =
+            err.downcast_ref::<alloy::transports::http::hyper_util::client::legacy::Error>()
+        {
+            if err.is_connect() {
+                return true;
+            }
+        }
+
+        if let Some(err) = err.downcast_ref::<io::Error>() {
+            if matches!(
+                err.kind(),
+                ErrorKind::ConnectionRefused
+                    | ErrorKind::ConnectionReset
+                    | ErrorKind::ConnectionAborted
+                    | ErrorKind::NotConnected
+                    | ErrorKind::BrokenPipe
+                    | ErrorKind::TimedOut
+            ) {
+                return true;
+            }
+        }
+
+        source = err.source();
+    }
+
+    false
+}
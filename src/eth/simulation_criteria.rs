@@ -0,0 +1,158 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use alloy::{
+    primitives::{Bytes, TxHash, U256},
+    rpc::types::mev::{EthCallBundleResponse, EthSendBundle},
+};
+
+use super::BroadcastResponse;
+
+/// The conditions a bundle simulation must meet for the bundle to be sent,
+/// when using [`EthBundleBuilder::simulate_then_send`].
+///
+/// By default, a transaction reverting while not being marked as possibly
+/// reverting fails the simulation.
+///
+/// [`EthBundleBuilder::simulate_then_send`]: crate::EthBundleBuilder::simulate_then_send
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimulationCriteria {
+    allow_unexpected_reverts: bool,
+    min_coinbase_payment: U256,
+}
+
+impl Default for SimulationCriteria {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SimulationCriteria {
+    /// Creates new [`SimulationCriteria`], rejecting unexpected reverts and
+    /// not requiring any coinbase payment.
+    pub const fn new() -> Self {
+        Self {
+            allow_unexpected_reverts: false,
+            min_coinbase_payment: U256::ZERO,
+        }
+    }
+
+    /// Sets whether transactions not marked as possibly reverting are
+    /// allowed to revert.
+    pub const fn allow_unexpected_reverts(mut self, allow: bool) -> Self {
+        self.allow_unexpected_reverts = allow;
+
+        self
+    }
+
+    /// Sets the minimum amount the bundle must pay to the coinbase, gas fees
+    /// included.
+    pub const fn with_min_coinbase_payment(mut self, min_coinbase_payment: U256) -> Self {
+        self.min_coinbase_payment = min_coinbase_payment;
+
+        self
+    }
+
+    /// Checks the simulation of the given bundle, as returned by the given
+    /// endpoint.
+    pub fn check(
+        &self,
+        bundle: &EthSendBundle,
+        endpoint_name: &str,
+        simulation: &EthCallBundleResponse,
+    ) -> Result<(), SimulationError> {
+        if !self.allow_unexpected_reverts {
+            let unexpected_revert = simulation.results.iter().find(|result| {
+                result.revert.is_some() && !bundle.reverting_tx_hashes.contains(&result.tx_hash)
+            });
+
+            if let Some(result) = unexpected_revert {
+                return Err(SimulationError::UnexpectedRevert {
+                    endpoint_name: endpoint_name.to_string(),
+                    tx_hash: result.tx_hash,
+                    revert: result.revert.clone().unwrap_or_default(),
+                });
+            }
+        }
+
+        if simulation.coinbase_diff < self.min_coinbase_payment {
+            return Err(SimulationError::InsufficientCoinbasePayment {
+                endpoint_name: endpoint_name.to_string(),
+                coinbase_diff: simulation.coinbase_diff,
+                min_coinbase_payment: self.min_coinbase_payment,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The reason a bundle has not been sent after being simulated.
+#[derive(Debug)]
+pub enum SimulationError {
+    /// None of the endpoints managed to simulate the bundle.
+    Unavailable(Vec<BroadcastResponse<EthCallBundleResponse>>),
+    /// A transaction not marked as possibly reverting has reverted.
+    UnexpectedRevert {
+        /// The name of the endpoint that simulated the bundle.
+        endpoint_name: String,
+        /// The hash of the reverted transaction.
+        tx_hash: TxHash,
+        /// The revert data.
+        revert: Bytes,
+    },
+    /// The bundle doesn't pay enough to the coinbase.
+    InsufficientCoinbasePayment {
+        /// The name of the endpoint that simulated the bundle.
+        endpoint_name: String,
+        /// The amount paid to the coinbase.
+        coinbase_diff: U256,
+        /// The minimum amount required.
+        min_coinbase_payment: U256,
+    },
+}
+
+impl Display for SimulationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unavailable(responses) => {
+                write!(f, "the bundle can't be simulated: ")?;
+
+                let errors = responses.iter().filter_map(|response| {
+                    let err = response.result.as_ref().err()?;
+                    Some((&response.endpoint_name, err))
+                });
+
+                for (i, (endpoint_name, err)) in errors.enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{endpoint_name}: {err}")?;
+                }
+
+                Ok(())
+            }
+            Self::UnexpectedRevert {
+                endpoint_name,
+                tx_hash,
+                revert,
+            } => write!(
+                f,
+                "{endpoint_name}: transaction {tx_hash} unexpectedly reverted ({revert})"
+            ),
+            Self::InsufficientCoinbasePayment {
+                endpoint_name,
+                coinbase_diff,
+                min_coinbase_payment,
+            } => write!(
+                f,
+                "{endpoint_name}: the bundle pays {coinbase_diff} to the coinbase, \
+                 less than {min_coinbase_payment}"
+            ),
+        }
+    }
+}
+
+impl Error for SimulationError {}
@@ -0,0 +1,240 @@
+use alloy::{
+    dyn_abi::{DynSolValue, JsonAbiExt},
+    json_abi::JsonAbi,
+    primitives::{Bytes, TxHash, U256},
+    rpc::types::mev::{EthCallBundleResponse, EthCallBundleTransactionResult},
+    sol_types::{Panic, Revert, SolError},
+};
+
+use crate::utils::per_gas;
+
+use super::BroadcastResponse;
+
+/// The reason a transaction reverted, decoded from its revert data.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RevertReason {
+    /// The transaction reverted with an `Error(string)`, e.g. using
+    /// `require`.
+    Error(String),
+    /// The transaction reverted with a `Panic(uint256)`, e.g. on an
+    /// arithmetic overflow.
+    Panic(U256),
+    /// The transaction reverted with a custom error found in the supplied
+    /// ABI.
+    Custom {
+        /// The name of the error.
+        name: String,
+        /// The decoded error arguments.
+        args: Vec<DynSolValue>,
+    },
+    /// The revert data can't be decoded.
+    Unknown(Bytes),
+}
+
+impl RevertReason {
+    /// Decodes the given revert data, looking up custom errors in the given
+    /// ABI, if any.
+    pub fn decode(data: &[u8], abi: Option<&JsonAbi>) -> Self {
+        if let Ok(revert) = Revert::abi_decode(data) {
+            return Self::Error(revert.reason);
+        }
+
+        if let Ok(panic) = Panic::abi_decode(data) {
+            return Self::Panic(panic.code);
+        }
+
+        if let (Some(abi), Some((selector, args))) = (abi, data.split_first_chunk::<4>()) {
+            let custom = abi
+                .errors()
+                .filter(|error| error.selector() == selector)
+                .find_map(|error| Some((error, error.abi_decode_input(args).ok()?)));
+
+            if let Some((error, args)) = custom {
+                return Self::Custom {
+                    name: error.name.clone(),
+                    args,
+                };
+            }
+        }
+
+        Self::Unknown(Bytes::copy_from_slice(data))
+    }
+}
+
+/// The analysis of a single transaction simulation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionAnalysis {
+    /// The hash of the transaction.
+    pub tx_hash: TxHash,
+    /// The gas used by the transaction.
+    pub gas_used: u64,
+    /// Why the transaction reverted, if it did.
+    pub revert: Option<RevertReason>,
+    /// The priority fee paid per unit of gas.
+    pub effective_priority_fee: U256,
+    /// The amount paid to the coinbase per unit of gas, gas fees included.
+    pub coinbase_payment_per_gas: U256,
+}
+
+impl TransactionAnalysis {
+    fn new(result: &EthCallBundleTransactionResult, abi: Option<&JsonAbi>) -> Self {
+        Self {
+            tx_hash: result.tx_hash,
+            gas_used: result.gas_used,
+            revert: result
+                .revert
+                .as_ref()
+                .map(|data| RevertReason::decode(data, abi)),
+            effective_priority_fee: per_gas(result.gas_fees, result.gas_used),
+            coinbase_payment_per_gas: per_gas(result.coinbase_diff, result.gas_used),
+        }
+    }
+}
+
+/// The analysis of a bundle simulation returned by an endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointSimulation {
+    /// The name of the endpoint that simulated the bundle.
+    pub endpoint_name: String,
+    /// The raw simulation.
+    pub response: EthCallBundleResponse,
+    /// The analysis of each transaction, in the bundle order.
+    pub transactions: Vec<TransactionAnalysis>,
+    /// The priority fee paid per unit of gas by the whole bundle.
+    pub effective_priority_fee: U256,
+    /// The amount paid to the coinbase per unit of gas by the whole bundle,
+    /// gas fees included.
+    pub coinbase_payment_per_gas: U256,
+}
+
+impl EndpointSimulation {
+    fn new(endpoint_name: String, response: EthCallBundleResponse, abi: Option<&JsonAbi>) -> Self {
+        Self {
+            endpoint_name,
+            transactions: response
+                .results
+                .iter()
+                .map(|result| TransactionAnalysis::new(result, abi))
+                .collect(),
+            effective_priority_fee: per_gas(response.gas_fees, response.total_gas_used),
+            coinbase_payment_per_gas: per_gas(response.coinbase_diff, response.total_gas_used),
+            response,
+        }
+    }
+
+    /// Returns the amount paid to the coinbase by the whole bundle, gas fees
+    /// included.
+    pub const fn coinbase_payment(&self) -> U256 {
+        self.response.coinbase_diff
+    }
+
+    /// Returns the transactions that reverted.
+    pub fn reverted(&self) -> impl Iterator<Item = &TransactionAnalysis> {
+        self.transactions.iter().filter(|tx| tx.revert.is_some())
+    }
+
+    /// Returns `true` if both simulations have the same outcome: the same gas
+    /// usage, coinbase payment and reverting transactions.
+    pub fn agrees_with(&self, other: &Self) -> bool {
+        self.response.total_gas_used == other.response.total_gas_used
+            && self.response.coinbase_diff == other.response.coinbase_diff
+            && self.transactions.len() == other.transactions.len()
+            && self
+                .transactions
+                .iter()
+                .zip(&other.transactions)
+                .all(|(a, b)| a.tx_hash == b.tx_hash && a.revert.is_some() == b.revert.is_some())
+    }
+}
+
+/// Aggregates the bundle simulations returned by several endpoints, e.g.
+/// using [`EthMevProviderExt::call_eth_bundle`].
+///
+/// [`EthMevProviderExt::call_eth_bundle`]: crate::EthMevProviderExt::call_eth_bundle
+#[derive(Debug)]
+pub struct SimulationReport {
+    /// The successful simulations.
+    pub simulations: Vec<EndpointSimulation>,
+    /// The endpoints that failed to simulate the bundle.
+    pub failures: Vec<BroadcastResponse<EthCallBundleResponse>>,
+}
+
+impl SimulationReport {
+    /// Creates a new [`SimulationReport`], only decoding the standard
+    /// `Error(string)` and `Panic(uint256)` reverts.
+    pub fn new(responses: Vec<BroadcastResponse<EthCallBundleResponse>>) -> Self {
+        Self::build(responses, None)
+    }
+
+    /// Creates a new [`SimulationReport`], also decoding the custom errors
+    /// found in the given ABI.
+    pub fn with_abi(
+        responses: Vec<BroadcastResponse<EthCallBundleResponse>>,
+        abi: &JsonAbi,
+    ) -> Self {
+        Self::build(responses, Some(abi))
+    }
+
+    /// Returns the simulation paying the most to the coinbase.
+    pub fn best(&self) -> Option<&EndpointSimulation> {
+        self.simulations
+            .iter()
+            .max_by_key(|simulation| simulation.coinbase_payment())
+    }
+
+    /// Returns the simulation paying the least to the coinbase.
+    pub fn worst(&self) -> Option<&EndpointSimulation> {
+        self.simulations
+            .iter()
+            .min_by_key(|simulation| simulation.coinbase_payment())
+    }
+
+    /// Returns `true` if the endpoints disagree on the outcome of the bundle.
+    pub fn has_disagreement(&self) -> bool {
+        !self.disagreeing_endpoints().is_empty()
+    }
+
+    /// Returns the names of the endpoints whose simulation doesn't agree with
+    /// the first successful one.
+    pub fn disagreeing_endpoints(&self) -> Vec<&str> {
+        let Some((reference, others)) = self.simulations.split_first() else {
+            return Vec::new();
+        };
+
+        others
+            .iter()
+            .filter(|simulation| !simulation.agrees_with(reference))
+            .map(|simulation| simulation.endpoint_name.as_str())
+            .collect()
+    }
+
+    fn build(
+        responses: Vec<BroadcastResponse<EthCallBundleResponse>>,
+        abi: Option<&JsonAbi>,
+    ) -> Self {
+        let mut simulations = Vec::new();
+        let mut failures = Vec::new();
+
+        for response in responses {
+            match response.result {
+                Ok(simulation) => simulations.push(EndpointSimulation::new(
+                    response.endpoint_name,
+                    simulation,
+                    abi,
+                )),
+                Err(_) => failures.push(response),
+            }
+        }
+
+        Self {
+            simulations,
+            failures,
+        }
+    }
+}
+
+impl From<Vec<BroadcastResponse<EthCallBundleResponse>>> for SimulationReport {
+    fn from(responses: Vec<BroadcastResponse<EthCallBundleResponse>>) -> Self {
+        Self::new(responses)
+    }
+}
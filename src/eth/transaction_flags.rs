@@ -0,0 +1,34 @@
+/// Tells builders how a transaction of a bundle may be handled, when added
+/// using [`EthBundleBuilder::add_signed_transaction_with_flags`] or
+/// [`EthBundleBuilder::add_transaction_request_with_flags`].
+///
+/// [`EthBundleBuilder::add_signed_transaction_with_flags`]: crate::EthBundleBuilder::add_signed_transaction_with_flags
+/// [`EthBundleBuilder::add_transaction_request_with_flags`]: crate::EthBundleBuilder::add_transaction_request_with_flags
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransactionFlags {
+    /// The bundle stays valid if the transaction reverts.
+    pub can_revert: bool,
+    /// The transaction can be removed from the bundle if it's invalid, e.g.
+    /// because its nonce has already been used.
+    pub can_drop: bool,
+}
+
+impl TransactionFlags {
+    /// The transaction must be included and succeed.
+    pub const NONE: Self = Self {
+        can_revert: false,
+        can_drop: false,
+    };
+
+    /// The transaction is allowed to revert.
+    pub const CAN_REVERT: Self = Self {
+        can_revert: true,
+        can_drop: false,
+    };
+
+    /// The transaction can be dropped from the bundle.
+    pub const CAN_DROP: Self = Self {
+        can_revert: false,
+        can_drop: true,
+    };
+}
@@ -10,11 +10,23 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 
 mod eth;
+#[cfg(feature = "revm")]
+pub use eth::BundleSimulator;
+#[cfg(feature = "serde")]
+pub use eth::{AuthMode, ConfigError, EndpointConfig, EndpointsConfig, InvalidEntry, SignerConfig};
 pub use eth::{
-    BroadcastableCall, Endpoints, EndpointsBuilder, EthBundleBuilder, EthMevProviderExt,
+    BroadcastResponse, BroadcastableCall, BundleAcknowledgement, BundleHandle, BundleOperation,
+    BundleSubmitter, BundleTracker, BundleTransaction, ClonableSigner, CoinbaseTip, Endpoint,
+    EndpointSimulation, Endpoints, EndpointsBuilder, EthBundleBuilder, EthMevProviderExt,
+    EthPrivateTransactionBuilder, FeeStrategy, IncludingBlock, InclusionStatus, KnownBuilder,
+    NonceTracker, RetryPolicy, RevertReason, SimulationCriteria, SimulationError, SimulationReport,
+    SubmissionOutcome, TransactionAnalysis, TransactionFlags,
 };
 
 mod mev_share;
-pub use mev_share::{MevShareBundleBuilder, MevShareProviderExt};
+pub use mev_share::{
+    MevShareBundleBuilder, MevShareProviderExt, FLASHBOTS_HOLESKY_RELAY_RPC_URL,
+    FLASHBOTS_RELAY_RPC_URL, FLASHBOTS_SEPOLIA_RELAY_RPC_URL,
+};
 
 mod utils;
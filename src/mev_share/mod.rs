@@ -4,4 +4,11 @@ pub use bundle_builder::MevShareBundleBuilder;
 mod provider_ext;
 pub use provider_ext::MevShareProviderExt;
 
-const FLASHBOTS_RELAY_RPC_URL: &str = "https://relay.flashbots.net";
+/// The Flashbots MEV-Share matchmaker RPC, on mainnet.
+pub const FLASHBOTS_RELAY_RPC_URL: &str = "https://relay.flashbots.net";
+
+/// The Flashbots MEV-Share matchmaker RPC, on Sepolia.
+pub const FLASHBOTS_SEPOLIA_RELAY_RPC_URL: &str = "https://relay-sepolia.flashbots.net";
+
+/// The Flashbots MEV-Share matchmaker RPC, on Holesky.
+pub const FLASHBOTS_HOLESKY_RELAY_RPC_URL: &str = "https://relay-holesky.flashbots.net";
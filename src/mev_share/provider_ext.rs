@@ -10,6 +10,7 @@ use async_trait::async_trait;
 use crate::{
     mev_share::{MevShareBundleBuilder, FLASHBOTS_RELAY_RPC_URL},
     utils::build_rpc_client,
+    BroadcastableCall, Endpoint, Endpoints,
 };
 
 /// Extension trait for sending and simulate MEV-Share bundles.
@@ -31,6 +32,25 @@ where
     where
         S: Signer + Clone + Send + Sync + 'static;
 
+    /// Submits a bundle to the given MEV-Share compatible matchmaker, signing
+    /// the request with the endpoint's signer.
+    async fn send_mev_bundle_via(
+        &self,
+        bundle: MevSendBundle,
+        endpoint: &Endpoint,
+    ) -> TransportResult<EthBundleHash>;
+
+    /// Submits a bundle to one or more MEV-Share compatible matchmaker(s),
+    /// signing each request with the endpoint's signer. It provides a
+    /// [`BroadcastResponse`] for each matchmaker.
+    ///
+    /// [`BroadcastResponse`]: crate::BroadcastResponse
+    fn send_mev_bundle_to(
+        &self,
+        bundle: MevSendBundle,
+        endpoints: &Endpoints,
+    ) -> BroadcastableCall<(MevSendBundle,), EthBundleHash>;
+
     /// Similar to `send_bundle` but instead of submitting a bundle to the
     /// matchmaker, it returns a simulation result. Only fully matched bundles
     /// can be simulated.
@@ -42,6 +62,15 @@ where
     ) -> TransportResult<SimBundleResponse>
     where
         S: Signer + Clone + Send + Sync + 'static;
+
+    /// Simulates a bundle using the given MEV-Share compatible matchmaker,
+    /// signing the request with the endpoint's signer.
+    async fn sim_mev_bundle_via(
+        &self,
+        bundle: MevSendBundle,
+        sim_overrides: SimBundleOverrides,
+        endpoint: &Endpoint,
+    ) -> TransportResult<SimBundleResponse>;
 }
 
 #[async_trait]
@@ -68,6 +97,27 @@ where
         MevBuilder::new_rpc(request).with_auth(signer).await
     }
 
+    async fn send_mev_bundle_via(
+        &self,
+        bundle: MevSendBundle,
+        endpoint: &Endpoint,
+    ) -> TransportResult<EthBundleHash> {
+        endpoint
+            .authenticated_call("mev_sendBundle", (bundle,))
+            .await
+    }
+
+    fn send_mev_bundle_to(
+        &self,
+        bundle: MevSendBundle,
+        endpoints: &Endpoints,
+    ) -> BroadcastableCall<(MevSendBundle,), EthBundleHash> {
+        BroadcastableCall::new(
+            endpoints,
+            self.client().make_request("mev_sendBundle", (bundle,)),
+        )
+    }
+
     async fn sim_mev_bundle<S>(
         &self,
         bundle: MevSendBundle,
@@ -82,4 +132,15 @@ where
 
         MevBuilder::new_rpc(request).with_auth(signer).await
     }
+
+    async fn sim_mev_bundle_via(
+        &self,
+        bundle: MevSendBundle,
+        sim_overrides: SimBundleOverrides,
+        endpoint: &Endpoint,
+    ) -> TransportResult<SimBundleResponse> {
+        endpoint
+            .authenticated_call("mev_simBundle", (bundle, sim_overrides))
+            .await
+    }
 }
@@ -1,4 +1,4 @@
-use alloy::rpc::client::RpcClient;
+use alloy::{primitives::U256, rpc::client::RpcClient};
 use url::Url;
 
 pub(crate) fn build_rpc_client(url: Url) -> RpcClient {
@@ -12,3 +12,9 @@ pub(crate) fn build_rpc_client(url: Url) -> RpcClient {
         }
     }
 }
+
+/// Divides the given amount by the gas used, returning zero if no gas has
+/// been used.
+pub(crate) fn per_gas(amount: U256, gas_used: u64) -> U256 {
+    amount.checked_div(U256::from(gas_used)).unwrap_or_default()
+}
@@ -0,0 +1,383 @@
+use std::{
+    future::pending,
+    io,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use alloy::{
+    primitives::B256,
+    providers::ProviderBuilder,
+    rpc::{
+        json_rpc::{ErrorPayload, RequestPacket, ResponsePacket},
+        types::mev::{EthBundleHash, EthSendBundle},
+    },
+    transports::{
+        mock::{Asserter, MockTransport},
+        TransportError, TransportErrorKind, TransportFut,
+    },
+};
+use alloy_mev::{Endpoint, Endpoints, EthMevProviderExt, RetryPolicy};
+use futures::StreamExt;
+use tower::Service;
+
+#[tokio::test]
+async fn test_broadcast_to_mocked_endpoints() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    let accepting = Asserter::new();
+    accepting.push_success(&EthBundleHash {
+        bundle_hash: B256::repeat_byte(1),
+    });
+
+    let endpoints = Endpoints::builder()
+        .add_endpoint(
+            Endpoint::from_transport(
+                "http://accepting".parse().unwrap(),
+                MockTransport::new(accepting),
+            )
+            .with_name("accepting"),
+        )
+        .add_endpoint(
+            Endpoint::from_transport(
+                "http://rejecting".parse().unwrap(),
+                MockTransport::new(Asserter::new()),
+            )
+            .with_name("rejecting"),
+        )
+        .build();
+
+    let responses = provider
+        .send_eth_bundle(EthSendBundle::default(), &endpoints)
+        .await;
+
+    assert_eq!(responses[0].endpoint_name, "accepting");
+    assert_eq!(
+        responses[0].result.as_ref().unwrap().bundle_hash,
+        B256::repeat_byte(1)
+    );
+    assert_eq!(responses[1].endpoint_name, "rejecting");
+    assert!(responses[1].result.is_err());
+}
+
+#[tokio::test]
+async fn test_retry_rate_limited_endpoint() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    let rate_limited = Asserter::new();
+    rate_limited.push_failure(ErrorPayload {
+        code: 429,
+        message: "rate limited".into(),
+        data: None,
+    });
+    rate_limited.push_success(&EthBundleHash {
+        bundle_hash: B256::repeat_byte(1),
+    });
+
+    let retry_policy = RetryPolicy::new(2).with_backoff(Duration::ZERO, Duration::ZERO);
+    let endpoints = Endpoints::builder()
+        .add_endpoint(
+            Endpoint::from_transport(
+                "http://rate-limited".parse().unwrap(),
+                MockTransport::new(rate_limited),
+            )
+            .with_retry_policy(retry_policy),
+        )
+        .build();
+
+    let responses = provider
+        .send_eth_bundle(EthSendBundle::default(), &endpoints)
+        .await;
+
+    assert!(responses[0].is_ok());
+}
+
+#[tokio::test]
+async fn test_send_bundles_on_multiple_blocks() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    let builder = Asserter::new();
+    for _ in 0..3 {
+        builder.push_success(&EthBundleHash {
+            bundle_hash: B256::repeat_byte(1),
+        });
+    }
+
+    let endpoints = Endpoints::builder()
+        .add_endpoint(Endpoint::from_transport(
+            "http://builder".parse().unwrap(),
+            MockTransport::new(builder),
+        ))
+        .build();
+
+    let bundles = provider
+        .bundle_builder()
+        .on_blocks(10..=12)
+        .with_replacement_uuid(String::from("uuid"))
+        .build_for_blocks();
+
+    assert_eq!(bundles.len(), 3);
+    assert!(bundles
+        .iter()
+        .all(|b| b.replacement_uuid.as_deref() == Some("uuid")));
+
+    let responses = provider.send_eth_bundles(bundles, &endpoints).await;
+
+    assert_eq!(responses.keys().copied().collect::<Vec<_>>(), [10, 11, 12]);
+    assert!(responses.values().all(|bundles| bundles.len() == 1));
+    assert!(responses.values().flatten().flatten().all(|r| r.is_ok()));
+}
+
+#[tokio::test]
+async fn test_send_bundles_on_same_block() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    let builder = Asserter::new();
+    for _ in 0..2 {
+        builder.push_success(&EthBundleHash {
+            bundle_hash: B256::repeat_byte(1),
+        });
+    }
+
+    let endpoints = Endpoints::builder()
+        .add_endpoint(Endpoint::from_transport(
+            "http://builder".parse().unwrap(),
+            MockTransport::new(builder),
+        ))
+        .build();
+
+    let bundle = provider.bundle_builder().on_block(10).build();
+    let responses = provider
+        .send_eth_bundles(vec![bundle.clone(), bundle], &endpoints)
+        .await;
+
+    assert_eq!(responses.keys().copied().collect::<Vec<_>>(), [10]);
+    assert_eq!(responses[&10].len(), 2);
+    assert!(responses[&10].iter().flatten().all(|r| r.is_ok()));
+}
+
+#[test]
+#[should_panic = "the range of target blocks is empty"]
+fn test_empty_target_blocks() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let _ = provider.bundle_builder().on_blocks(10..=5);
+}
+
+/// A transport never answering.
+#[derive(Clone)]
+struct HungTransport;
+
+impl Service<RequestPacket> for HungTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _req: RequestPacket) -> Self::Future {
+        Box::pin(pending())
+    }
+}
+
+#[tokio::test]
+async fn test_timeout_hung_endpoint() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    let accepting = Asserter::new();
+    let endpoints = Endpoints::builder()
+        .add_endpoint(
+            Endpoint::from_transport(
+                "http://accepting".parse().unwrap(),
+                MockTransport::new(accepting.clone()),
+            )
+            .with_name("accepting"),
+        )
+        .add_endpoint(
+            Endpoint::from_transport("http://hung".parse().unwrap(), HungTransport)
+                .with_name("hung"),
+        )
+        .build();
+
+    let bundle_hash = EthBundleHash {
+        bundle_hash: B256::repeat_byte(1),
+    };
+
+    accepting.push_success(&bundle_hash);
+
+    let responses = provider
+        .send_eth_bundle(EthSendBundle::default(), &endpoints)
+        .with_timeout(Duration::from_millis(50))
+        .await;
+
+    assert!(responses[0].is_ok());
+    assert!(responses[1]
+        .result
+        .as_ref()
+        .is_err_and(|err| err.to_string().contains("timed out")));
+
+    // A deadline in the past times out the hung endpoint right away
+    accepting.push_success(&bundle_hash);
+
+    let responses = provider
+        .send_eth_bundle(EthSendBundle::default(), &endpoints)
+        .with_deadline(Instant::now())
+        .await;
+
+    assert!(responses[1].result.is_err());
+
+    // A timeout too large to be represented is ignored, in favor of the deadline
+    accepting.push_success(&bundle_hash);
+
+    let responses = provider
+        .send_eth_bundle(EthSendBundle::default(), &endpoints)
+        .with_timeout(Duration::MAX)
+        .with_deadline(Instant::now() + Duration::from_millis(50))
+        .await;
+
+    assert!(responses[0].is_ok());
+    assert!(responses[1].result.is_err());
+}
+
+/// A transport answering after a delay.
+#[derive(Clone)]
+struct DelayedTransport {
+    delay: Duration,
+    inner: MockTransport,
+}
+
+impl Service<RequestPacket> for DelayedTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: RequestPacket) -> Self::Future {
+        let delay = self.delay;
+        let response = self.inner.call(req);
+
+        Box::pin(async move {
+            tokio::time::sleep(delay).await;
+            response.await
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_stream_fastest_endpoint_first() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    let slow = Asserter::new();
+    let fast = Asserter::new();
+    let endpoints = Endpoints::builder()
+        .add_endpoint(
+            Endpoint::from_transport(
+                "http://slow".parse().unwrap(),
+                DelayedTransport {
+                    delay: Duration::from_millis(100),
+                    inner: MockTransport::new(slow.clone()),
+                },
+            )
+            .with_name("slow"),
+        )
+        .add_endpoint(
+            Endpoint::from_transport(
+                "http://fast".parse().unwrap(),
+                MockTransport::new(fast.clone()),
+            )
+            .with_name("fast"),
+        )
+        .build();
+
+    let bundle_hash = EthBundleHash {
+        bundle_hash: B256::repeat_byte(1),
+    };
+
+    slow.push_success(&bundle_hash);
+    fast.push_success(&bundle_hash);
+
+    let stream = provider
+        .send_eth_bundle(EthSendBundle::default(), &endpoints)
+        .with_timeout(Duration::from_millis(500))
+        .into_stream();
+
+    // The timeout only starts when the stream is first polled
+    tokio::time::sleep(Duration::from_millis(600)).await;
+
+    let responses = stream.collect::<Vec<_>>().await;
+
+    assert_eq!(responses[0].endpoint_name, "fast");
+    assert_eq!(responses[1].endpoint_name, "slow");
+    assert!(responses.iter().all(|response| response.is_ok()));
+}
+
+/// A transport always failing with the given error, counting its calls.
+#[derive(Clone)]
+struct FailingTransport {
+    calls: Arc<AtomicUsize>,
+    error: fn() -> TransportError,
+}
+
+impl Service<RequestPacket> for FailingTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _req: RequestPacket) -> Self::Future {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+
+        let error = (self.error)();
+        Box::pin(async move { Err(error) })
+    }
+}
+
+#[tokio::test]
+async fn test_retry_connection_errors_only() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+    let retry_policy = RetryPolicy::new(3).with_backoff(Duration::ZERO, Duration::ZERO);
+
+    let refused = FailingTransport {
+        calls: Arc::default(),
+        error: || TransportErrorKind::custom(io::Error::from(io::ErrorKind::ConnectionRefused)),
+    };
+    let signing_failed = FailingTransport {
+        calls: Arc::default(),
+        error: || TransportErrorKind::custom_str("failed to sign the request"),
+    };
+
+    let endpoints = Endpoints::builder()
+        .add_endpoint(
+            Endpoint::from_transport("http://refused".parse().unwrap(), refused.clone())
+                .with_retry_policy(retry_policy),
+        )
+        .add_endpoint(
+            Endpoint::from_transport(
+                "http://signing-failed".parse().unwrap(),
+                signing_failed.clone(),
+            )
+            .with_retry_policy(retry_policy),
+        )
+        .build();
+
+    let responses = provider
+        .send_eth_bundle(EthSendBundle::default(), &endpoints)
+        .await;
+
+    assert!(responses.iter().all(|response| response.result.is_err()));
+    assert_eq!(refused.calls.load(Ordering::SeqCst), 3);
+    assert_eq!(signing_failed.calls.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,89 @@
+use alloy::{
+    hex,
+    primitives::{address, b256},
+    providers::ProviderBuilder,
+    rpc::types::BlockNumberOrTag,
+    transports::mock::Asserter,
+};
+use alloy_mev::{EthMevProviderExt, TransactionFlags};
+
+#[tokio::test]
+async fn test_transaction_flags() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    // tx 0x0722b12f3f46877a5251ecce105263ccf9f5390f9fab5ecc51e4858705fd8667
+    let tx = hex!("02f876018204ed843b9aca0085012a05f20082a22794825001ac81d9348f71f2dadd717335ac0ab4a9fe89056a6418b50586000080c001a0e491ff34326cd113b9a1a34f2f82f57727d70dc78577a97ae54dd3a2b43b8583a06c956d5b1dae0514360d56186870c5d50771fc4b204931a5ace7e19baa7f0a86");
+    let hash = b256!("0x0722b12f3f46877a5251ecce105263ccf9f5390f9fab5ecc51e4858705fd8667");
+
+    let bundle = provider
+        .bundle_builder()
+        .add_signed_transaction_with_flags(
+            tx.into(),
+            TransactionFlags {
+                can_revert: true,
+                can_drop: true,
+            },
+        )
+        .build();
+
+    assert_eq!(bundle.reverting_tx_hashes, [hash]);
+    assert_eq!(bundle.dropping_tx_hashes, [hash]);
+
+    let bundle = provider
+        .bundle_builder()
+        .add_signed_transaction_with_flags(tx.into(), TransactionFlags::CAN_DROP)
+        .build();
+
+    assert!(bundle.reverting_tx_hashes.is_empty());
+    assert_eq!(bundle.dropping_tx_hashes, [hash]);
+}
+
+#[tokio::test]
+async fn test_refund() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    // tx 0x0722b12f3f46877a5251ecce105263ccf9f5390f9fab5ecc51e4858705fd8667
+    let tx = hex!("02f876018204ed843b9aca0085012a05f20082a22794825001ac81d9348f71f2dadd717335ac0ab4a9fe89056a6418b50586000080c001a0e491ff34326cd113b9a1a34f2f82f57727d70dc78577a97ae54dd3a2b43b8583a06c956d5b1dae0514360d56186870c5d50771fc4b204931a5ace7e19baa7f0a86");
+    let hash = b256!("0x0722b12f3f46877a5251ecce105263ccf9f5390f9fab5ecc51e4858705fd8667");
+    let recipient = address!("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+
+    let bundle = provider
+        .bundle_builder()
+        .add_signed_transaction(tx.into())
+        .with_refund_percent(90)
+        .unwrap()
+        .with_refund_recipient(recipient)
+        .add_refund_tx(hash)
+        .unwrap()
+        .build();
+
+    assert_eq!(bundle.refund_percent, Some(90));
+    assert_eq!(bundle.refund_recipient, Some(recipient));
+    assert_eq!(bundle.refund_tx_hashes, [hash]);
+
+    assert!(provider.bundle_builder().with_refund_percent(100).is_err());
+    assert!(provider.bundle_builder().add_refund_tx(hash).is_err());
+}
+
+#[test]
+fn test_call_bundle_state_block() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    // The parent of a block after the next one isn't mined yet
+    let call_bundle = provider
+        .bundle_builder()
+        .on_blocks(102..=104)
+        .build_call_bundle();
+    assert_eq!(call_bundle.block_number, 102);
+    assert_eq!(call_bundle.state_block_number, BlockNumberOrTag::Latest);
+
+    let call_bundle = provider
+        .bundle_builder()
+        .on_block(102)
+        .with_state_block(BlockNumberOrTag::Number(101))
+        .build_call_bundle();
+    assert_eq!(
+        call_bundle.state_block_number,
+        BlockNumberOrTag::Number(101)
+    );
+}
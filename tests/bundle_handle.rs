@@ -0,0 +1,76 @@
+use alloy::{
+    primitives::{Bytes, B256},
+    providers::ProviderBuilder,
+    rpc::types::mev::EthBundleHash,
+    transports::mock::{Asserter, MockTransport},
+};
+use alloy_mev::{BundleOperation, Endpoint, Endpoints, EthMevProviderExt};
+
+#[tokio::test]
+async fn test_replace_then_cancel() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    let flashbots = Asserter::new();
+    let titan = Asserter::new();
+    let endpoints = Endpoints::builder()
+        .add_endpoint(
+            Endpoint::from_transport(
+                "http://flashbots".parse().unwrap(),
+                MockTransport::new(flashbots.clone()),
+            )
+            .with_name("flashbots"),
+        )
+        .add_endpoint(
+            Endpoint::from_transport(
+                "http://titan".parse().unwrap(),
+                MockTransport::new(titan.clone()),
+            )
+            .with_name("titan"),
+        )
+        .build();
+
+    let bundle_hash = EthBundleHash {
+        bundle_hash: B256::repeat_byte(1),
+    };
+
+    flashbots.push_success(&bundle_hash);
+    titan.push_success(&bundle_hash);
+
+    let mut handle = provider
+        .bundle_builder()
+        .add_signed_transaction(Bytes::from_static(&[1]))
+        .on_block(20247245)
+        .send(&endpoints)
+        .await;
+
+    let uuid = handle.replacement_uuid().to_string();
+
+    assert_eq!(handle.bundle().replacement_uuid.as_ref(), Some(&uuid));
+    assert!(handle.history()[0].is_complete());
+
+    // Titan fails to replace the bundle
+    flashbots.push_success(&bundle_hash);
+    titan.push_failure_msg("unavailable");
+
+    let replacement = provider
+        .bundle_builder()
+        .add_signed_transaction(Bytes::from_static(&[2]))
+        .on_block(20247245)
+        .build();
+    let acknowledgement = handle.replace(replacement).await;
+
+    assert_eq!(acknowledgement.operation, BundleOperation::Replace);
+    assert_eq!(acknowledgement.acknowledged, ["flashbots"]);
+    assert_eq!(acknowledgement.rejected, ["titan"]);
+    assert_eq!(handle.bundle().replacement_uuid.as_ref(), Some(&uuid));
+    assert_eq!(handle.bundle().txs, [Bytes::from_static(&[2])]);
+
+    flashbots.push_success(&());
+    titan.push_success(&());
+
+    let acknowledgement = handle.cancel().await;
+
+    assert_eq!(acknowledgement.operation, BundleOperation::Cancel);
+    assert!(acknowledgement.is_complete());
+    assert_eq!(handle.history().len(), 3);
+}
@@ -0,0 +1,101 @@
+use std::{
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use alloy::{
+    primitives::{B256, U256},
+    providers::ProviderBuilder,
+    rpc::{
+        json_rpc::{RequestPacket, ResponsePacket},
+        types::mev::{BundleStats, UserStats},
+    },
+    signers::local::PrivateKeySigner,
+    transports::{
+        mock::{Asserter, MockTransport},
+        TransportError, TransportFut,
+    },
+};
+use alloy_mev::{Endpoint, EthMevProviderExt};
+use tower::Service;
+
+/// A mocked transport recording the requests it receives.
+#[derive(Clone)]
+struct RecordingTransport {
+    requests: Arc<Mutex<Vec<String>>>,
+    inner: MockTransport,
+}
+
+impl Service<RequestPacket> for RecordingTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: RequestPacket) -> Self::Future {
+        let request = req.clone().serialize().unwrap().get().to_string();
+        self.requests.lock().unwrap().push(request);
+
+        self.inner.call(req)
+    }
+}
+
+#[tokio::test]
+async fn test_get_bundle_stats() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    let relay = Asserter::new();
+    relay.push_success(&BundleStats::Unknown);
+
+    let endpoint = Endpoint::from_transport(
+        "http://relay".parse().unwrap(),
+        MockTransport::new(relay.clone()),
+    );
+
+    let unauthenticated = provider
+        .get_bundle_stats(B256::ZERO, 20247245, &endpoint)
+        .await;
+    assert!(unauthenticated.is_err());
+
+    let stats = provider
+        .get_bundle_stats(
+            B256::ZERO,
+            20247245,
+            &endpoint.with_signer(PrivateKeySigner::random()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(stats, BundleStats::Unknown);
+}
+
+#[tokio::test]
+async fn test_get_user_stats() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    let relay = Asserter::new();
+    let transport = RecordingTransport {
+        requests: Arc::default(),
+        inner: MockTransport::new(relay.clone()),
+    };
+    let user_stats = UserStats {
+        is_high_priority: true,
+        all_time_validator_payments: U256::from(1_000_000_000_u64),
+        ..Default::default()
+    };
+
+    relay.push_success(&user_stats);
+
+    let endpoint = Endpoint::from_transport("http://relay".parse().unwrap(), transport.clone())
+        .with_signer(PrivateKeySigner::random());
+
+    let stats = provider.get_user_stats(20247245, &endpoint).await.unwrap();
+
+    assert_eq!(stats, user_stats);
+
+    let requests = transport.requests.lock().unwrap();
+    assert!(requests[0].contains(r#""method":"flashbots_getUserStatsV2""#));
+    assert!(requests[0].contains(r#""params":[{"blockNumber":"0x134f2cd"}]"#));
+}
@@ -0,0 +1,158 @@
+use alloy::{
+    consensus::{self, Transaction, TxEnvelope},
+    eips::Decodable2718,
+    primitives::{address, Address, U256, U64},
+    providers::ProviderBuilder,
+    rpc::types::{mev::EthCallBundleResponse, Block, Header},
+    signers::local::PrivateKeySigner,
+    transports::mock::{Asserter, MockTransport},
+};
+use alloy_mev::{CoinbaseTip, Endpoint, Endpoints, EthMevProviderExt, FeeStrategy};
+
+fn parent() -> Block<()> {
+    Block::empty(Header::new(consensus::Header {
+        number: 100,
+        gas_limit: 30_000_000,
+        gas_used: 15_000_000,
+        base_fee_per_gas: Some(1_000_000_000),
+        ..Default::default()
+    }))
+}
+
+fn builder(name: &str, asserter: Asserter) -> Endpoint {
+    Endpoint::from_transport(
+        format!("http://{name}").parse().unwrap(),
+        MockTransport::new(asserter),
+    )
+    .with_name(name)
+}
+
+#[tokio::test]
+async fn test_add_coinbase_tip() {
+    let asserter = Asserter::new();
+    let signer = PrivateKeySigner::random();
+    let sender = signer.address();
+    let provider = ProviderBuilder::new()
+        .wallet(signer)
+        .connect_mocked_client(asserter.clone());
+    let fee_recipient = address!("0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5");
+
+    // The parent block, the gas limit, the nonce, and the chain ID
+    asserter.push_success(&parent());
+    asserter.push_success(&U64::from(21000));
+    asserter.push_success(&U64::from(7));
+    asserter.push_success(&U64::from(1));
+
+    let builder = provider
+        .bundle_builder()
+        .on_block(102)
+        .with_fee_strategy(FeeStrategy::Fixed(0))
+        .add_coinbase_tip(
+            sender,
+            CoinbaseTip::transfer(fee_recipient),
+            U256::from(1000),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(builder.transactions()[0].sender, sender);
+    assert_eq!(builder.transactions()[0].nonce, 7);
+
+    let bundle = builder.build();
+    let tip = TxEnvelope::decode_2718(&mut bundle.txs[0].as_ref()).unwrap();
+
+    assert_eq!(tip.to(), Some(fee_recipient));
+    assert_eq!(tip.value(), U256::from(1000));
+    // The base fee of block 101 is unchanged, and may go up by 12.5% in block 102
+    assert_eq!(tip.max_fee_per_gas(), 1_125_000_000);
+    assert_eq!(tip.max_priority_fee_per_gas(), Some(0));
+}
+
+#[tokio::test]
+async fn test_add_simulated_coinbase_tip() {
+    let asserter = Asserter::new();
+    let signer = PrivateKeySigner::random();
+    let sender = signer.address();
+    let provider = ProviderBuilder::new()
+        .wallet(signer)
+        .connect_mocked_client(asserter.clone());
+    let fee_recipient = address!("0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5");
+
+    let first = Asserter::new();
+    first.push_success(&EthCallBundleResponse {
+        coinbase_diff: U256::from(42_000),
+        ..Default::default()
+    });
+    let second = Asserter::new();
+    second.push_success(&EthCallBundleResponse {
+        coinbase_diff: U256::from(63_000),
+        ..Default::default()
+    });
+
+    let endpoints = Endpoints::builder()
+        .add_endpoint(builder("first", first))
+        .add_endpoint(builder("second", second))
+        .build();
+
+    // The parent block, the gas limit, the nonce, and the chain ID
+    asserter.push_success(&parent());
+    asserter.push_success(&U64::from(21000));
+    asserter.push_success(&U64::from(7));
+    asserter.push_success(&U64::from(1));
+
+    let bundle = provider
+        .bundle_builder()
+        .on_block(102)
+        .with_fee_strategy(FeeStrategy::Fixed(0))
+        .add_simulated_coinbase_tip(
+            sender,
+            CoinbaseTip::transfer(fee_recipient),
+            &endpoints,
+            |report| report.best().unwrap().coinbase_payment() / U256::from(2),
+        )
+        .await
+        .unwrap()
+        .build();
+
+    let tip = TxEnvelope::decode_2718(&mut bundle.txs[0].as_ref()).unwrap();
+
+    // Half of what the best simulation pays to the coinbase
+    assert_eq!(tip.to(), Some(fee_recipient));
+    assert_eq!(tip.value(), U256::from(31_500));
+}
+
+#[tokio::test]
+async fn test_add_simulated_coinbase_tip_without_simulation() {
+    let signer = PrivateKeySigner::random();
+    let sender = signer.address();
+    let provider = ProviderBuilder::new()
+        .wallet(signer)
+        .connect_mocked_client(Asserter::new());
+
+    let first = Asserter::new();
+    first.push_failure_msg("eth_callBundle is not supported");
+    let second = Asserter::new();
+    second.push_failure_msg("eth_callBundle is not supported");
+
+    let endpoints = Endpoints::builder()
+        .add_endpoint(builder("first", first))
+        .add_endpoint(builder("second", second))
+        .build();
+
+    let result = provider
+        .bundle_builder()
+        .on_block(102)
+        .add_simulated_coinbase_tip(
+            sender,
+            CoinbaseTip::transfer(Address::ZERO),
+            &endpoints,
+            |_| unreachable!("the amount is only derived from a simulation"),
+        )
+        .await;
+
+    assert!(result
+        .err()
+        .unwrap()
+        .to_string()
+        .contains("the bundle can't be simulated to derive the coinbase tip"));
+}
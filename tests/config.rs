@@ -0,0 +1,57 @@
+use alloy_mev::{ConfigError, Endpoints, EndpointsConfig};
+
+#[test]
+fn test_endpoints_from_config() {
+    std::env::set_var(
+        "TEST_BUNDLE_SIGNER_KEY",
+        "0x0000000000000000000000000000000000000000000000000000000000123456",
+    );
+
+    let config = EndpointsConfig::from_toml(
+        r#"
+        [[builders]]
+        name = "flashbots"
+        signer = { env = "TEST_BUNDLE_SIGNER_KEY" }
+
+        [[builders]]
+        name = "beaverbuild"
+
+        [[builders]]
+        name = "local"
+        url = "http://localhost:8545"
+        "#,
+    )
+    .unwrap();
+
+    let endpoints = Endpoints::from_config(&config).unwrap();
+    let names = endpoints
+        .iter()
+        .map(|e| e.name.as_str())
+        .collect::<Vec<_>>();
+
+    assert_eq!(names, ["flashbots", "beaverbuild", "local"]);
+    assert!(endpoints.get("flashbots").unwrap().signer.is_some());
+}
+
+#[test]
+fn test_invalid_entries_are_reported() {
+    let config = EndpointsConfig::from_json(
+        r#"{
+            "builders": [
+                { "name": "beaverbuild" },
+                { "name": "unknown" },
+                { "name": "bad-url", "url": "not a url" },
+                { "name": "titan" },
+                { "name": "beaverbuild" }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let Err(ConfigError::InvalidEntries(entries)) = Endpoints::from_config(&config) else {
+        panic!("the configuration should be invalid");
+    };
+
+    let indexes = entries.iter().map(|e| e.index).collect::<Vec<_>>();
+    assert_eq!(indexes, [1, 2, 3, 4]);
+}
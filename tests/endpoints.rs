@@ -0,0 +1,46 @@
+use alloy::signers::local::PrivateKeySigner;
+use alloy_mev::{Endpoints, KnownBuilder};
+
+#[test]
+fn test_named_endpoints() {
+    let signer = PrivateKeySigner::random();
+    let titan = KnownBuilder::by_name("titan-europe").unwrap();
+
+    let endpoints = Endpoints::builder()
+        .beaverbuild()
+        .authenticated_known_builder(titan, signer)
+        .known_builder(&KnownBuilder::RSYNC)
+        .unwrap()
+        .endpoint("http://localhost:8545".parse().unwrap())
+        .named_endpoint("local", "http://127.0.0.1:8545".parse().unwrap())
+        .build();
+
+    let names = endpoints
+        .iter()
+        .map(|e| e.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        ["beaverbuild", "titan-europe", "rsync", "localhost", "local"]
+    );
+
+    let titan = endpoints.get("titan-europe").unwrap();
+    assert!(titan.signer.is_some());
+    assert_eq!(titan.url.as_str(), "https://eu.rpc.titanbuilder.xyz/");
+    assert!(endpoints.get("beaverbuild").unwrap().signer.is_none());
+
+    assert!(KnownBuilder::by_name("unknown").is_none());
+    assert!(KnownBuilder::FLASHBOTS.supports("eth_callBundle"));
+}
+
+#[test]
+fn test_known_builder_requiring_auth() {
+    let builder = KnownBuilder::by_name("flashbots").unwrap();
+    let result = Endpoints::builder().known_builder(builder);
+
+    assert!(result
+        .err()
+        .unwrap()
+        .to_string()
+        .contains("flashbots requires authentication"));
+}
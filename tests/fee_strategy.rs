@@ -0,0 +1,77 @@
+use alloy::{
+    consensus::Header,
+    network::TransactionBuilder,
+    primitives::{Address, U256},
+    providers::ProviderBuilder,
+    rpc::types::{self, Block, TransactionRequest},
+    signers::local::PrivateKeySigner,
+    transports::mock::Asserter,
+};
+use alloy_mev::{EthMevProviderExt, FeeStrategy};
+
+#[test]
+fn test_predict_base_fee() {
+    let parent = Header {
+        number: 100,
+        gas_limit: 30_000_000,
+        gas_used: 30_000_000,
+        base_fee_per_gas: Some(1_000_000_000),
+        ..Default::default()
+    };
+
+    // The parent is full, so the base fee goes up by 12.5% per block
+    assert_eq!(
+        FeeStrategy::predict_base_fee(&parent, 101),
+        Some(1_125_000_000)
+    );
+    assert_eq!(
+        FeeStrategy::predict_base_fee(&parent, 102),
+        Some(1_265_625_000)
+    );
+    assert_eq!(FeeStrategy::predict_base_fee(&parent, 100), None);
+
+    // The next block's base fee is exact, the following ones are the worst case
+    let empty_parent = Header {
+        gas_used: 0,
+        ..parent
+    };
+    assert_eq!(
+        FeeStrategy::predict_base_fee(&empty_parent, 101),
+        Some(875_000_000)
+    );
+    assert_eq!(
+        FeeStrategy::predict_base_fee(&empty_parent, 102),
+        Some(984_375_000)
+    );
+}
+
+#[tokio::test]
+async fn test_percentile_out_of_range() {
+    let asserter = Asserter::new();
+    let provider = ProviderBuilder::new()
+        .wallet(PrivateKeySigner::random())
+        .connect_mocked_client(asserter.clone());
+
+    asserter.push_success(&Block::<()>::empty(types::Header::new(Header {
+        number: 100,
+        base_fee_per_gas: Some(1_000_000_000),
+        ..Default::default()
+    })));
+
+    let result = provider
+        .bundle_builder()
+        .on_block(101)
+        .with_fee_strategy(FeeStrategy::Percentile(150.0))
+        .add_transaction_request(
+            TransactionRequest::default()
+                .with_to(Address::ZERO)
+                .with_value(U256::from(1)),
+        )
+        .await;
+
+    assert!(result
+        .err()
+        .unwrap()
+        .to_string()
+        .contains("the percentile 150 isn't between 0 and 100"));
+}
@@ -0,0 +1,127 @@
+use alloy::{
+    hex,
+    network::TransactionBuilder,
+    primitives::{address, Address, U256, U64},
+    providers::ProviderBuilder,
+    rpc::types::TransactionRequest,
+    signers::local::PrivateKeySigner,
+    transports::mock::Asserter,
+};
+use alloy_mev::{EthMevProviderExt, NonceTracker};
+
+#[tokio::test]
+async fn test_shared_nonce_tracker() {
+    let asserter = Asserter::new();
+    let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+    let sender = address!("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+
+    let nonces = NonceTracker::new();
+    assert_eq!(nonces.next_nonce(sender), None);
+
+    // tx 0x0722b12f3f46877a5251ecce105263ccf9f5390f9fab5ecc51e4858705fd8667
+    let tx = hex!("02f876018204ed843b9aca0085012a05f20082a22794825001ac81d9348f71f2dadd717335ac0ab4a9fe89056a6418b50586000080c001a0e491ff34326cd113b9a1a34f2f82f57727d70dc78577a97ae54dd3a2b43b8583a06c956d5b1dae0514360d56186870c5d50771fc4b204931a5ace7e19baa7f0a86");
+
+    asserter.push_success(&U64::from(1));
+
+    provider
+        .bundle_builder()
+        .with_nonce_tracker(nonces.clone())
+        .add_validated_transaction(tx.into())
+        .await
+        .unwrap();
+
+    assert_eq!(nonces.reserve(sender), Some(0x04ee));
+    assert_eq!(nonces.next_nonce(sender), Some(0x04ef));
+
+    nonces.reset(sender);
+    assert_eq!(nonces.next_nonce(sender), None);
+}
+
+fn transfer() -> TransactionRequest {
+    TransactionRequest::default()
+        .with_to(Address::ZERO)
+        .with_value(U256::from(1))
+        .with_max_fee_per_gas(2_000_000_000)
+        .with_max_priority_fee_per_gas(0)
+}
+
+#[tokio::test]
+async fn test_sequential_nonces_from_sender() {
+    let asserter = Asserter::new();
+    let signer = PrivateKeySigner::random();
+    let sender = signer.address();
+    let provider = ProviderBuilder::new()
+        .wallet(signer)
+        .connect_mocked_client(asserter.clone());
+
+    // The nonce and the chain ID of the first transaction
+    asserter.push_success(&U64::from(7));
+    asserter.push_success(&U64::from(1));
+    // The gas estimation of the second transaction reverts
+    asserter.push_failure_msg("execution reverted");
+
+    let nonces = NonceTracker::new();
+    let builder = provider
+        .bundle_builder()
+        .with_nonce_tracker(nonces.clone())
+        .add_transaction_request(transfer().with_from(sender).with_gas_limit(21_000))
+        .await
+        .unwrap();
+
+    assert!(builder
+        .add_transaction_request(transfer().with_from(sender))
+        .await
+        .is_err());
+    // The nonce reserved for the failed transaction is given back
+    assert_eq!(nonces.next_nonce(sender), Some(8));
+
+    let builder = provider
+        .bundle_builder()
+        .with_nonce_tracker(nonces)
+        .add_transaction_request(transfer().with_from(sender).with_gas_limit(21_000))
+        .await
+        .unwrap()
+        .add_transaction_request(transfer().with_from(sender).with_gas_limit(21_000))
+        .await
+        .unwrap();
+
+    let nonces = builder.transactions().iter().map(|tx| tx.nonce);
+    assert_eq!(nonces.collect::<Vec<_>>(), [8, 9]);
+    assert!(asserter.read_q().is_empty());
+}
+
+#[tokio::test]
+async fn test_sequential_nonces_without_sender() {
+    let asserter = Asserter::new();
+    let signer = PrivateKeySigner::random();
+    let sender = signer.address();
+    // The nonces aren't cached, so the provider fills the same one twice
+    let provider = ProviderBuilder::new()
+        .disable_recommended_fillers()
+        .with_gas_estimation()
+        .with_simple_nonce_management()
+        .fetch_chain_id()
+        .wallet(signer)
+        .connect_mocked_client(asserter.clone());
+
+    // The nonce and the chain ID of the first transaction, and then the
+    // nonce of the second one, as the pending transaction is unknown
+    asserter.push_success(&U64::from(7));
+    asserter.push_success(&U64::from(1));
+    asserter.push_success(&U64::from(7));
+
+    let builder = provider
+        .bundle_builder()
+        .add_transaction_request(transfer().with_gas_limit(21_000))
+        .await
+        .unwrap()
+        .add_transaction_request(transfer().with_gas_limit(21_000))
+        .await
+        .unwrap();
+
+    let transactions = builder.transactions();
+    assert!(transactions.iter().all(|tx| tx.sender == sender));
+    assert_eq!(transactions[0].nonce, 7);
+    assert_eq!(transactions[1].nonce, 8);
+    assert!(asserter.read_q().is_empty());
+}
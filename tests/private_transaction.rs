@@ -0,0 +1,74 @@
+use alloy::{
+    primitives::{Bytes, B256},
+    providers::ProviderBuilder,
+    rpc::types::mev::EthCancelPrivateTransaction,
+    transports::mock::{Asserter, MockTransport},
+};
+use alloy_mev::{Endpoint, Endpoints, EthMevProviderExt};
+
+#[tokio::test]
+async fn test_broadcast_private_transaction() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    let flashbots = Asserter::new();
+    let titan = Asserter::new();
+    let endpoints = Endpoints::builder()
+        .add_endpoint(
+            Endpoint::from_transport(
+                "http://flashbots".parse().unwrap(),
+                MockTransport::new(flashbots.clone()),
+            )
+            .with_name("flashbots"),
+        )
+        .add_endpoint(
+            Endpoint::from_transport(
+                "http://titan".parse().unwrap(),
+                MockTransport::new(titan.clone()),
+            )
+            .with_name("titan"),
+        )
+        .build();
+
+    let request = provider
+        .private_transaction_builder(Bytes::from_static(&[1]))
+        .with_max_block_number(20247250)
+        .with_fast_mode(true)
+        .build();
+
+    assert_eq!(request.max_block_number, Some(20247250));
+    assert_eq!(request.preferences.fast, Some(true));
+
+    let tx_hash = B256::repeat_byte(1);
+
+    flashbots.push_success(&tx_hash);
+    titan.push_failure_msg("unavailable");
+
+    let responses = provider
+        .private_transaction_builder(Bytes::from_static(&[1]))
+        .send(&endpoints)
+        .await;
+
+    assert_eq!(responses.len(), 2);
+    assert!(responses
+        .iter()
+        .any(|response| response.endpoint_name == "flashbots"
+            && matches!(response.result, Ok(hash) if hash == tx_hash)));
+    assert!(responses
+        .iter()
+        .any(|response| response.endpoint_name == "titan" && response.result.is_err()));
+
+    flashbots.push_success(&true);
+    titan.push_success(&false);
+
+    let responses = provider
+        .cancel_eth_private_transaction_to(EthCancelPrivateTransaction { tx_hash }, &endpoints)
+        .await;
+
+    assert_eq!(
+        responses
+            .iter()
+            .filter(|response| matches!(response.result, Ok(true)))
+            .count(),
+        1
+    );
+}
@@ -0,0 +1,68 @@
+use alloy::{
+    primitives::B256,
+    providers::ProviderBuilder,
+    rpc::client::RpcClient,
+    rpc::types::mev::{EthBundleHash, MevSendBundle},
+    signers::local::PrivateKeySigner,
+    transports::mock::{Asserter, MockTransport},
+};
+use alloy_mev::{Endpoint, Endpoints, MevShareProviderExt};
+
+#[tokio::test]
+async fn test_send_mev_bundle_via_endpoint() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    let relay = Asserter::new();
+    relay.push_success(&EthBundleHash {
+        bundle_hash: B256::repeat_byte(1),
+    });
+
+    let endpoint = Endpoint::from_transport(
+        "http://relay".parse().unwrap(),
+        MockTransport::new(relay.clone()),
+    );
+
+    // The matchmaker requires the request to be signed
+    let unauthenticated = provider
+        .send_mev_bundle_via(MevSendBundle::default(), &endpoint)
+        .await;
+    assert!(unauthenticated.is_err());
+
+    let response = provider
+        .send_mev_bundle_via(
+            MevSendBundle::default(),
+            &endpoint.with_signer(PrivateKeySigner::random()),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.bundle_hash, B256::repeat_byte(1));
+}
+
+#[tokio::test]
+async fn test_send_mev_bundle_to_endpoints() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    let endpoints = Endpoints::builder()
+        .client_factory(|_| {
+            let relay = Asserter::new();
+            relay.push_success(&EthBundleHash {
+                bundle_hash: B256::repeat_byte(1),
+            });
+            RpcClient::new(MockTransport::new(relay), true)
+        })
+        .authenticated_endpoint("http://first".parse().unwrap(), PrivateKeySigner::random())
+        .authenticated_endpoint("http://second".parse().unwrap(), PrivateKeySigner::random())
+        .build();
+
+    let responses = provider
+        .send_mev_bundle_to(MevSendBundle::default(), &endpoints)
+        .await;
+
+    let names = responses
+        .iter()
+        .map(|r| r.endpoint_name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["first", "second"]);
+    assert!(responses.iter().all(|r| r.is_ok()));
+}
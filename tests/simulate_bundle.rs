@@ -0,0 +1,72 @@
+use alloy::{
+    consensus,
+    eips::eip2930::{AccessList, AccessListItem, AccessListResult},
+    hex,
+    primitives::{address, Bytes, B256, U256, U64},
+    providers::ProviderBuilder,
+    rpc::types::{mev::EthSendBundle, Block, Header},
+    transports::mock::Asserter,
+};
+use alloy_mev::EthMevProviderExt;
+
+#[tokio::test]
+async fn test_simulate_bundle() {
+    let asserter = Asserter::new();
+    let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+
+    // tx 0x0722b12f3f46877a5251ecce105263ccf9f5390f9fab5ecc51e4858705fd8667
+    let tx = hex!("02f876018204ed843b9aca0085012a05f20082a22794825001ac81d9348f71f2dadd717335ac0ab4a9fe89056a6418b50586000080c001a0e491ff34326cd113b9a1a34f2f82f57727d70dc78577a97ae54dd3a2b43b8583a06c956d5b1dae0514360d56186870c5d50771fc4b204931a5ace7e19baa7f0a86");
+
+    let bundle = EthSendBundle {
+        txs: vec![tx.into()],
+        block_number: 20247245,
+        ..Default::default()
+    };
+
+    let simulator = provider.bundle_simulator(&bundle).unwrap();
+    let sender = address!("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+    let coinbase = address!("0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5");
+
+    // The state block, and the chain ID
+    asserter.push_success(&Block::<()>::empty(Header::new(consensus::Header {
+        number: 20247244,
+        beneficiary: coinbase,
+        gas_limit: 30_000_000,
+        base_fee_per_gas: Some(1_000_000_000),
+        ..Default::default()
+    })));
+    asserter.push_success(&U64::from(1));
+
+    // The access list of the transaction
+    let recipient = address!("0x825001ac81d9348f71f2dadd717335ac0ab4a9fe");
+    asserter.push_success(&AccessListResult {
+        access_list: AccessList(vec![AccessListItem {
+            address: recipient,
+            storage_keys: vec![B256::ZERO],
+        }]),
+        gas_used: U256::from(21000),
+        error: None,
+    });
+
+    // The nonce, balance and code of the coinbase, the sender and the
+    // recipient, and then the storage slot of the access list, all fetched
+    // before the execution
+    for (nonce, balance) in [(0, U256::ZERO), (0x04ed, U256::MAX >> 128), (0, U256::ZERO)] {
+        asserter.push_success(&U64::from(nonce));
+        asserter.push_success(&balance);
+        asserter.push_success(&Bytes::new());
+    }
+    asserter.push_success(&U256::ZERO);
+
+    let response = simulator.simulate().await.unwrap();
+    let result = &response.results[0];
+
+    assert_eq!(response.state_block_number, 20247244);
+    assert_eq!(result.from_address, sender);
+    assert_eq!(result.gas_used, 21000);
+    assert_eq!(result.coinbase_diff, U256::from(21_000_000_000_000_u64));
+    assert_eq!(result.gas_price, U256::from(1_000_000_000));
+    assert_eq!(result.eth_sent_to_coinbase, U256::ZERO);
+    assert!(result.revert.is_none());
+    assert!(asserter.read_q().is_empty());
+}
@@ -0,0 +1,68 @@
+use alloy::{
+    primitives::{Bytes, B256},
+    providers::ProviderBuilder,
+    rpc::types::mev::{EthBundleHash, EthCallBundleResponse, EthCallBundleTransactionResult},
+    transports::mock::{Asserter, MockTransport},
+};
+use alloy_mev::{Endpoint, Endpoints, EthMevProviderExt, SimulationCriteria, SimulationError};
+
+#[tokio::test]
+async fn test_simulate_then_send() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    let builder = Asserter::new();
+    let endpoints = Endpoints::builder()
+        .add_endpoint(
+            Endpoint::from_transport(
+                "http://builder".parse().unwrap(),
+                MockTransport::new(builder.clone()),
+            )
+            .with_name("builder"),
+        )
+        .build();
+
+    let tx_hash = B256::repeat_byte(1);
+    let simulation = EthCallBundleResponse {
+        results: vec![EthCallBundleTransactionResult {
+            tx_hash,
+            revert: Some(Bytes::new()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    // The reverting transaction is not expected to revert
+    builder.push_success(&simulation);
+
+    let result = provider
+        .bundle_builder()
+        .add_signed_transaction(Bytes::new())
+        .on_block(20247245)
+        .simulate_then_send(&endpoints, SimulationCriteria::new())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(SimulationError::UnexpectedRevert { tx_hash: hash, .. }) if hash == tx_hash
+    ));
+
+    // The reverting transaction is allowed to revert
+    builder.push_success(&simulation);
+    builder.push_success(&EthBundleHash {
+        bundle_hash: B256::repeat_byte(2),
+    });
+
+    let responses = provider
+        .bundle_builder()
+        .add_signed_transaction(Bytes::new())
+        .add_reverting_tx(tx_hash)
+        .on_block(20247245)
+        .simulate_then_send(&endpoints, SimulationCriteria::new())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        responses[0].result.as_ref().unwrap().bundle_hash,
+        B256::repeat_byte(2)
+    );
+}
@@ -0,0 +1,95 @@
+use alloy::{
+    dyn_abi::DynSolValue,
+    json_abi::JsonAbi,
+    primitives::{B256, U256},
+    providers::ProviderBuilder,
+    rpc::types::mev::{EthCallBundle, EthCallBundleResponse, EthCallBundleTransactionResult},
+    sol,
+    sol_types::{Revert, SolError},
+    transports::mock::{Asserter, MockTransport},
+};
+use alloy_mev::{Endpoint, Endpoints, EthMevProviderExt, RevertReason, SimulationReport};
+
+sol! {
+    error InsufficientOutput(uint256 amountOut);
+}
+
+fn simulation(revert: Vec<u8>, coinbase_diff: u64) -> EthCallBundleResponse {
+    EthCallBundleResponse {
+        results: vec![EthCallBundleTransactionResult {
+            tx_hash: B256::repeat_byte(1),
+            coinbase_diff: U256::from(coinbase_diff),
+            gas_fees: U256::from(21_000),
+            gas_used: 21_000,
+            revert: Some(revert.into()),
+            ..Default::default()
+        }],
+        coinbase_diff: U256::from(coinbase_diff),
+        gas_fees: U256::from(21_000),
+        total_gas_used: 21_000,
+        ..Default::default()
+    }
+}
+
+fn endpoint(name: &str, asserter: Asserter) -> Endpoint {
+    Endpoint::from_transport(
+        format!("http://{name}").parse().unwrap(),
+        MockTransport::new(asserter),
+    )
+    .with_name(name)
+}
+
+#[tokio::test]
+async fn test_simulation_report() {
+    let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+
+    let first = Asserter::new();
+    first.push_success(&simulation(
+        Revert::from("too little received").abi_encode(),
+        42_000,
+    ));
+    let second = Asserter::new();
+    second.push_success(&simulation(
+        InsufficientOutput {
+            amountOut: U256::from(1),
+        }
+        .abi_encode(),
+        63_000,
+    ));
+
+    let endpoints = Endpoints::builder()
+        .add_endpoint(endpoint("first", first))
+        .add_endpoint(endpoint("second", second))
+        .add_endpoint(endpoint("failing", Asserter::new()))
+        .build();
+
+    let responses = provider
+        .call_eth_bundle(EthCallBundle::default(), &endpoints)
+        .await;
+
+    let abi = JsonAbi::parse(["error InsufficientOutput(uint256 amountOut)"]).unwrap();
+    let report = SimulationReport::with_abi(responses, &abi);
+
+    assert_eq!(report.simulations.len(), 2);
+    assert_eq!(report.failures[0].endpoint_name, "failing");
+
+    let first = &report.simulations[0];
+    assert_eq!(
+        first.transactions[0].revert,
+        Some(RevertReason::Error("too little received".into()))
+    );
+    assert_eq!(first.transactions[0].effective_priority_fee, U256::from(1));
+    assert_eq!(first.coinbase_payment_per_gas, U256::from(2));
+
+    let best = report.best().unwrap();
+    assert_eq!(best.endpoint_name, "second");
+    assert_eq!(
+        best.transactions[0].revert,
+        Some(RevertReason::Custom {
+            name: "InsufficientOutput".into(),
+            args: vec![DynSolValue::Uint(U256::from(1), 256)],
+        })
+    );
+
+    assert_eq!(report.disagreeing_endpoints(), ["second"]);
+}
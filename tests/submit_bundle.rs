@@ -0,0 +1,222 @@
+use alloy::{
+    consensus::{self, Receipt, ReceiptEnvelope, ReceiptWithBloom},
+    hex,
+    primitives::{address, Address, B256, U64},
+    providers::ProviderBuilder,
+    rpc::types::{mev::EthBundleHash, Block, Header, TransactionReceipt},
+    transports::mock::{Asserter, MockTransport},
+};
+use alloy_mev::{Endpoint, Endpoints, EthMevProviderExt, InclusionStatus, SubmissionOutcome};
+
+// tx 0x0722b12f3f46877a5251ecce105263ccf9f5390f9fab5ecc51e4858705fd8667
+const TX: [u8; 121] = hex!("02f876018204ed843b9aca0085012a05f20082a22794825001ac81d9348f71f2dadd717335ac0ab4a9fe89056a6418b50586000080c001a0e491ff34326cd113b9a1a34f2f82f57727d70dc78577a97ae54dd3a2b43b8583a06c956d5b1dae0514360d56186870c5d50771fc4b204931a5ace7e19baa7f0a86");
+
+fn endpoints(builder: &Asserter) -> Endpoints {
+    Endpoints::builder()
+        .add_endpoint(
+            Endpoint::from_transport(
+                "http://builder".parse().unwrap(),
+                MockTransport::new(builder.clone()),
+            )
+            .with_name("builder"),
+        )
+        .build()
+}
+
+fn bundle_hash() -> EthBundleHash {
+    EthBundleHash {
+        bundle_hash: B256::repeat_byte(1),
+    }
+}
+
+/// Pushes the responses of the first round: the block filter, the current
+/// block, and the transaction neither mined nor front-run.
+fn push_first_round(asserter: &Asserter) {
+    asserter.push_success(&U64::from(1));
+    asserter.push_success(&U64::from(100));
+    asserter.push_success(&Option::<()>::None);
+    asserter.push_success(&U64::from(0x04ed));
+}
+
+#[tokio::test]
+async fn test_submit_until_settled() {
+    let asserter = Asserter::new();
+    let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+    let builder = Asserter::new();
+    let endpoints = endpoints(&builder);
+
+    let bundle = provider
+        .bundle_builder()
+        .add_signed_transaction(TX.into())
+        .build();
+
+    push_first_round(&asserter);
+    builder.push_success(&bundle_hash());
+
+    // A new block is mined, and it includes the transaction
+    asserter.push_success(&vec![B256::repeat_byte(2)]);
+    asserter.push_success(&U64::from(101));
+    asserter.push_success(&receipt(101));
+    asserter.push_success(&Block::<()>::empty(Header::new(consensus::Header {
+        number: 101,
+        ..Default::default()
+    })));
+
+    let mut rounds = Vec::new();
+
+    let outcome = provider
+        .bundle_submitter(bundle, &endpoints)
+        .on_submission(|block_number, responses| rounds.push((block_number, responses.len())))
+        .run()
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        outcome,
+        SubmissionOutcome::Settled(InclusionStatus::Included(block)) if block.number == 101
+    ));
+    assert_eq!(rounds, [(101, 1)]);
+}
+
+#[tokio::test]
+async fn test_submit_until_expired() {
+    let asserter = Asserter::new();
+    let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+    let builder = Asserter::new();
+    let endpoints = endpoints(&builder);
+
+    let bundle = provider
+        .bundle_builder()
+        .add_signed_transaction(TX.into())
+        .build();
+
+    push_first_round(&asserter);
+    builder.push_success(&bundle_hash());
+
+    // A new block is mined without including the transaction
+    asserter.push_success(&vec![B256::repeat_byte(2)]);
+    asserter.push_success(&U64::from(101));
+    asserter.push_success(&Option::<()>::None);
+    asserter.push_success(&U64::from(0x04ed));
+
+    let outcome = provider
+        .bundle_submitter(bundle, &endpoints)
+        .until_block(101)
+        .run()
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        outcome,
+        SubmissionOutcome::Expired { last_block: 101, responses } if responses[0].is_ok()
+    ));
+}
+
+#[tokio::test]
+async fn test_submit_rejected() {
+    let asserter = Asserter::new();
+    let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+    let builder = Asserter::new();
+    let endpoints = endpoints(&builder);
+
+    let bundle = provider
+        .bundle_builder()
+        .add_signed_transaction(TX.into())
+        .build();
+
+    push_first_round(&asserter);
+    builder.push_failure_msg("invalid bundle");
+
+    let outcome = provider
+        .bundle_submitter(bundle, &endpoints)
+        .run()
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        outcome,
+        SubmissionOutcome::Rejected { block_number: 101, responses } if responses[0].result.is_err()
+    ));
+}
+
+#[tokio::test]
+async fn test_submit_after_transport_error() {
+    let asserter = Asserter::new();
+    let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+    let builder = Asserter::new();
+    let endpoints = endpoints(&builder);
+
+    let bundle = provider
+        .bundle_builder()
+        .add_signed_transaction(TX.into())
+        .build();
+
+    // The builder doesn't answer the first submission, which isn't a
+    // rejection of the bundle
+    push_first_round(&asserter);
+
+    // A new block is mined without including the transaction
+    asserter.push_success(&vec![B256::repeat_byte(2)]);
+    asserter.push_success(&U64::from(101));
+    asserter.push_success(&Option::<()>::None);
+    asserter.push_success(&U64::from(0x04ed));
+
+    let mut rounds = Vec::new();
+
+    let outcome = provider
+        .bundle_submitter(bundle, &endpoints)
+        .until_block(101)
+        .on_submission(|block_number, responses| {
+            rounds.push((block_number, responses[0].is_ok()));
+        })
+        .run()
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        outcome,
+        SubmissionOutcome::Expired { last_block: 101, responses } if !responses[0].is_rejected()
+    ));
+    assert_eq!(rounds, [(101, false)]);
+}
+
+#[tokio::test]
+async fn test_submit_without_endpoints() {
+    let asserter = Asserter::new();
+    let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+    let endpoints = Endpoints::default();
+
+    let bundle = provider
+        .bundle_builder()
+        .add_signed_transaction(TX.into())
+        .build();
+
+    let outcome = provider.bundle_submitter(bundle, &endpoints).run().await;
+
+    assert!(outcome.is_err());
+    assert!(asserter.read_q().is_empty());
+}
+
+fn receipt(block_number: u64) -> TransactionReceipt {
+    TransactionReceipt {
+        inner: ReceiptEnvelope::Eip1559(ReceiptWithBloom {
+            receipt: Receipt {
+                status: true.into(),
+                cumulative_gas_used: 21000,
+                logs: Vec::new(),
+            },
+            logs_bloom: Default::default(),
+        }),
+        transaction_hash: B256::repeat_byte(3),
+        transaction_index: Some(0),
+        block_hash: Some(B256::repeat_byte(2)),
+        block_number: Some(block_number),
+        gas_used: 21000,
+        effective_gas_price: 1_000_000_000,
+        blob_gas_used: None,
+        blob_gas_price: None,
+        from: address!("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"),
+        to: Some(Address::repeat_byte(4)),
+        contract_address: None,
+    }
+}
@@ -0,0 +1,183 @@
+use alloy::{
+    consensus::{
+        self, Receipt, ReceiptEnvelope, ReceiptWithBloom, SignableTransaction, TxEip1559,
+        TxEnvelope,
+    },
+    eips::Encodable2718,
+    hex,
+    network::TxSignerSync,
+    primitives::{address, Address, Bytes, B256, U64},
+    providers::ProviderBuilder,
+    rpc::types::{mev::EthSendBundle, Block, Header, TransactionReceipt},
+    signers::local::PrivateKeySigner,
+    transports::mock::Asserter,
+};
+use alloy_mev::{EthMevProviderExt, InclusionStatus};
+
+// tx 0x0722b12f3f46877a5251ecce105263ccf9f5390f9fab5ecc51e4858705fd8667
+const TX: [u8; 121] = hex!("02f876018204ed843b9aca0085012a05f20082a22794825001ac81d9348f71f2dadd717335ac0ab4a9fe89056a6418b50586000080c001a0e491ff34326cd113b9a1a34f2f82f57727d70dc78577a97ae54dd3a2b43b8583a06c956d5b1dae0514360d56186870c5d50771fc4b204931a5ace7e19baa7f0a86");
+
+#[tokio::test]
+async fn test_front_run_bundle() {
+    let asserter = Asserter::new();
+    let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+
+    let bundle = EthSendBundle {
+        txs: vec![TX.into()],
+        block_number: 20247245,
+        ..Default::default()
+    };
+
+    let tracker = provider.track_bundle(&bundle).unwrap();
+    let sent = tracker.transactions()[0];
+    assert_eq!(sent.nonce, 0x04ed);
+
+    // The transaction has not been mined...
+    asserter.push_success(&Option::<()>::None);
+    // ... but its nonce has been consumed
+    asserter.push_success(&U64::from(0x04ee));
+
+    let status = tracker.status().await.unwrap();
+
+    assert_eq!(
+        status,
+        InclusionStatus::FrontRun {
+            tx_hash: sent.hash,
+            sender: sent.sender,
+            nonce: sent.nonce,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_included_bundle() {
+    let asserter = Asserter::new();
+    let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+    let coinbase = address!("0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5");
+
+    let bundle = EthSendBundle {
+        txs: vec![TX.into()],
+        block_number: 20247245,
+        ..Default::default()
+    };
+
+    let tracker = provider.track_bundle(&bundle).unwrap();
+
+    asserter.push_success(&receipt(20247245));
+    asserter.push_success(&block(20247245, coinbase));
+
+    let status = tracker.status().await.unwrap();
+
+    assert!(matches!(
+        status,
+        InclusionStatus::Included(block) if block.number == 20247245 && block.coinbase == coinbase
+    ));
+}
+
+#[tokio::test]
+async fn test_partially_included_bundle() {
+    let asserter = Asserter::new();
+    let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+    let coinbase = address!("0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5");
+
+    let bundle = EthSendBundle {
+        txs: vec![TX.into(), signed_tx()],
+        block_number: 20247245,
+        ..Default::default()
+    };
+
+    let tracker = provider.track_bundle(&bundle).unwrap();
+    let hashes = tracker
+        .transactions()
+        .iter()
+        .map(|tx| tx.hash)
+        .collect::<Vec<_>>();
+
+    // Only the first transaction has been mined
+    asserter.push_success(&receipt(20247245));
+    asserter.push_success(&Option::<()>::None);
+    asserter.push_success(&block(20247245, coinbase));
+
+    let status = tracker.status().await.unwrap();
+
+    assert!(matches!(
+        status,
+        InclusionStatus::PartiallyIncluded { block, included, missing }
+            if block.number == 20247245 && included == [hashes[0]] && missing == [hashes[1]]
+    ));
+}
+
+#[tokio::test]
+async fn test_mined_outside_bundle() {
+    let asserter = Asserter::new();
+    let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+
+    let bundle = EthSendBundle {
+        txs: vec![TX.into()],
+        block_number: 20247245,
+        ..Default::default()
+    };
+
+    let tracker = provider.track_bundle(&bundle).unwrap();
+    let hash = tracker.transactions()[0].hash;
+
+    // The transaction has been mined in a block not targeted by the bundle
+    asserter.push_success(&receipt(20247250));
+
+    let status = tracker.status().await.unwrap();
+
+    assert_eq!(
+        status,
+        InclusionStatus::MinedOutsideBundle {
+            mined: vec![(hash, 20247250)],
+            missing: Vec::new(),
+        }
+    );
+}
+
+fn signed_tx() -> Bytes {
+    let signer = PrivateKeySigner::random();
+    let mut tx = TxEip1559 {
+        chain_id: 1,
+        gas_limit: 21000,
+        to: Address::repeat_byte(4).into(),
+        ..Default::default()
+    };
+    let signature = signer.sign_transaction_sync(&mut tx).unwrap();
+
+    TxEnvelope::from(tx.into_signed(signature))
+        .encoded_2718()
+        .into()
+}
+
+fn block(number: u64, coinbase: Address) -> Block<()> {
+    Block::empty(Header::new(consensus::Header {
+        number,
+        beneficiary: coinbase,
+        ..Default::default()
+    }))
+}
+
+fn receipt(block_number: u64) -> TransactionReceipt {
+    TransactionReceipt {
+        inner: ReceiptEnvelope::Eip1559(ReceiptWithBloom {
+            receipt: Receipt {
+                status: true.into(),
+                cumulative_gas_used: 21000,
+                logs: Vec::new(),
+            },
+            logs_bloom: Default::default(),
+        }),
+        transaction_hash: B256::repeat_byte(3),
+        transaction_index: Some(0),
+        block_hash: Some(B256::repeat_byte(2)),
+        block_number: Some(block_number),
+        gas_used: 21000,
+        effective_gas_price: 1_000_000_000,
+        blob_gas_used: None,
+        blob_gas_price: None,
+        from: address!("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"),
+        to: Some(Address::repeat_byte(4)),
+        contract_address: None,
+    }
+}
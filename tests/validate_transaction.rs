@@ -0,0 +1,54 @@
+use alloy::{
+    hex,
+    primitives::{Bytes, U64},
+    providers::ProviderBuilder,
+    transports::mock::Asserter,
+};
+use alloy_mev::EthMevProviderExt;
+
+// tx 0x0722b12f3f46877a5251ecce105263ccf9f5390f9fab5ecc51e4858705fd8667
+const TX: [u8; 121] = hex!("02f876018204ed843b9aca0085012a05f20082a22794825001ac81d9348f71f2dadd717335ac0ab4a9fe89056a6418b50586000080c001a0e491ff34326cd113b9a1a34f2f82f57727d70dc78577a97ae54dd3a2b43b8583a06c956d5b1dae0514360d56186870c5d50771fc4b204931a5ace7e19baa7f0a86");
+
+#[tokio::test]
+async fn test_add_validated_transaction() {
+    let asserter = Asserter::new();
+    let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+
+    asserter.push_success(&U64::from(1));
+
+    let builder = provider
+        .bundle_builder()
+        .add_validated_transaction(TX.into())
+        .await
+        .unwrap();
+
+    assert_eq!(builder.transactions()[0].nonce, 0x04ed);
+    assert_eq!(builder.build().txs.len(), 1);
+}
+
+#[tokio::test]
+async fn test_reject_invalid_transaction() {
+    let asserter = Asserter::new();
+    let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+
+    let malformed = provider
+        .bundle_builder()
+        .add_validated_transaction(Bytes::from_static(&[0x02, 0x00]))
+        .await;
+    assert!(malformed.is_err());
+
+    let trailing_bytes = provider
+        .bundle_builder()
+        .add_validated_transaction([TX.as_slice(), &[0xde, 0xad]].concat().into())
+        .await;
+    assert!(trailing_bytes.is_err());
+
+    // The provider is on Sepolia
+    asserter.push_success(&U64::from(11155111));
+
+    let wrong_chain = provider
+        .bundle_builder()
+        .add_validated_transaction(TX.into())
+        .await;
+    assert!(wrong_chain.is_err());
+}